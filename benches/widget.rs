@@ -0,0 +1,133 @@
+//! A simple benchmark measuring the [`iced_loading_indicator::Widget`]'s construction
+//! and per-frame costs for varying numbers of circles, using a headless `tiny-skia` renderer.
+//!
+//! Run with `cargo bench --bench widget`.
+
+const NUMBER_OF_CIRCLES: [usize; 5] = [8, 12, 24, 48, 96];
+const ITERATIONS: u32 = 10_000;
+const SIZE: f32 = 100.0;
+/// The fixed trail length of the last column, with which the number of circles
+/// whose color changes at each tick does not grow with the number of circles.
+const TRAIL_LENGTH: usize = 4;
+
+type Renderer = iced::Renderer<iced::Theme>;
+type Element = iced::Element<'static, (), Renderer>;
+
+fn widget(number_of_circles: usize) -> Element {
+    iced_loading_indicator::Widget::with_size(SIZE)
+        .number_of_circles(number_of_circles)
+        .into()
+}
+
+fn widget_with_trail(number_of_circles: usize) -> Element {
+    iced_loading_indicator::Widget::with_size(SIZE)
+        .number_of_circles(number_of_circles)
+        .trail(iced_loading_indicator::Trail::new().length(TRAIL_LENGTH))
+        .into()
+}
+
+fn renderer() -> Renderer {
+    Renderer::TinySkia(iced_tiny_skia::Renderer::new(iced_tiny_skia::Backend::new(
+        Default::default(),
+    )))
+}
+
+/// Returns the average duration of `f`, in microseconds.
+fn measure(mut f: impl FnMut()) -> f64 {
+    let start = std::time::Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed().as_secs_f64() * 1_000_000.0 / f64::from(ITERATIONS)
+}
+
+/// Returns the average duration, in microseconds, of moving the `element` to its next
+/// index and drawing it, which only requires drawing the circles whose color changed.
+fn tick_and_draw(renderer: &mut Renderer, mut element: Element) -> f64 {
+    let theme = iced::Theme::Dark;
+    let style = Default::default();
+    let cursor = iced::mouse::Cursor::Unavailable;
+    let mut clipboard = iced_tiny_skia::core::clipboard::Null;
+
+    let mut tree = iced::advanced::widget::Tree::new(&element);
+    let node = element
+        .as_widget()
+        .layout(renderer, &iced::advanced::layout::Limits::NONE);
+    let layout = iced::advanced::Layout::new(&node);
+    let viewport = layout.bounds();
+
+    let mut now = std::time::Instant::now();
+    measure(|| {
+        now += std::time::Duration::from_millis(iced_loading_indicator::DEFAULT_TICK_DURATION_MS);
+        let mut messages = Vec::new();
+        let mut shell = iced::advanced::Shell::new(&mut messages);
+        let _ = element.as_widget_mut().on_event(
+            &mut tree,
+            iced::Event::Window(iced::window::Event::RedrawRequested(now)),
+            layout,
+            cursor,
+            renderer,
+            &mut clipboard,
+            &mut shell,
+            &viewport,
+        );
+        element
+            .as_widget()
+            .draw(&tree, renderer, &theme, &style, layout, cursor, &viewport);
+        iced::advanced::Renderer::clear(renderer);
+    })
+}
+
+fn main() {
+    let mut renderer = renderer();
+    let theme = iced::Theme::Dark;
+    let style = Default::default();
+    let cursor = iced::mouse::Cursor::Unavailable;
+
+    println!(
+        "{:>8} | {:>16} | {:>16} | {:>16} | {:>24}",
+        "circles",
+        "construct (µs)",
+        "redraw (µs)",
+        "tick+draw (µs)",
+        format!("tick+draw, trail {} (µs)", TRAIL_LENGTH)
+    );
+
+    for number_of_circles in NUMBER_OF_CIRCLES {
+        let construct = measure(|| {
+            let element = widget(number_of_circles);
+            std::hint::black_box(iced::advanced::widget::Tree::new(&element));
+        });
+
+        let element = widget(number_of_circles);
+        let tree = iced::advanced::widget::Tree::new(&element);
+        let node = element
+            .as_widget()
+            .layout(&renderer, &iced::advanced::layout::Limits::NONE);
+        let layout = iced::advanced::Layout::new(&node);
+        let viewport = layout.bounds();
+
+        // Redrawing an unchanged indicator reuses the cached geometry.
+        let redraw = measure(|| {
+            element.as_widget().draw(
+                &tree,
+                &mut renderer,
+                &theme,
+                &style,
+                layout,
+                cursor,
+                &viewport,
+            );
+            iced::advanced::Renderer::clear(&mut renderer);
+        });
+
+        let tick_and_draw_default = tick_and_draw(&mut renderer, widget(number_of_circles));
+        let tick_and_draw_trail =
+            tick_and_draw(&mut renderer, widget_with_trail(number_of_circles));
+
+        println!(
+            "{:>8} | {:>16.3} | {:>16.3} | {:>16.3} | {:>24.3}",
+            number_of_circles, construct, redraw, tick_and_draw_default, tick_and_draw_trail
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use iced::Application as _;
+
+const LOADING_DURATION_MS: u64 = 2000;
+
+fn main() -> iced::Result {
+    MyApp::run(iced::Settings {
+        window: iced::window::Settings {
+            size: (400, 200),
+            position: iced::window::Position::Specific(50, 800),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Default)]
+struct MyApp {
+    is_saving: bool,
+    saved_count: usize,
+}
+
+#[derive(Debug, Clone)]
+enum MyMessage {
+    SaveButton,
+    Saved,
+}
+
+impl iced::Application for MyApp {
+    type Executor = iced::executor::Default;
+    type Flags = ();
+    type Message = MyMessage;
+    type Theme = iced::theme::Theme;
+
+    fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
+        (Default::default(), iced::Command::none())
+    }
+
+    fn title(&self) -> String {
+        "Loading Button Demo".into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        iced::theme::Theme::Dark
+    }
+
+    fn subscription(&self) -> iced::Subscription<Self::Message> {
+        // Simulates some work taking a while to complete.
+        if self.is_saving {
+            iced::time::every(std::time::Duration::from_millis(LOADING_DURATION_MS))
+                .map(|_| MyMessage::Saved)
+        } else {
+            iced::Subscription::none()
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
+        // The spinner takes the label's place (and the button's text color) while saving,
+        // and pressing the button does nothing until saving completes.
+        let button = iced_loading_indicator::LoadingButton::new(iced::widget::text("Save changes"))
+            .on_press(MyMessage::SaveButton)
+            .loading(self.is_saving)
+            .padding([10, 20]);
+
+        iced::widget::container(
+            iced::widget::column!(
+                button,
+                iced::widget::text(format!("Saved {} time(s)", self.saved_count))
+            )
+            .align_items(iced::Alignment::Center)
+            .spacing(20),
+        )
+        .width(iced::Length::Fill)
+        .height(iced::Length::Fill)
+        .center_x()
+        .center_y()
+        .padding(20)
+        .into()
+    }
+
+    fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
+        match message {
+            MyMessage::SaveButton => {
+                self.is_saving = true;
+                iced::Command::none()
+            }
+            MyMessage::Saved => {
+                self.is_saving = false;
+                self.saved_count += 1;
+                iced::Command::none()
+            }
+        }
+    }
+}
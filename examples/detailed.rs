@@ -17,8 +17,8 @@ const INDICATOR_CUSTOM_COLOR_DEFAULT_B: u8 = 0x11;
 
 const SIDEBAR_WIDTH: f32 = 300.0;
 
-const INPUT_ID_CUSTOM_COLOR_R: &'static str = "custom_color_input_r";
-const INPUT_ID_CUSTOM_SPEED: &'static str = "custom_speed_input";
+const INPUT_ID_CUSTOM_COLOR_R: &str = "custom_color_input_r";
+const INPUT_ID_CUSTOM_SPEED: &str = "custom_speed_input";
 
 fn main() -> iced::Result {
     MyApp::run(iced::Settings {
@@ -35,8 +35,13 @@ fn main() -> iced::Result {
 #[derive(Debug)]
 struct MyApp {
     dark_mode: bool,
+    smooth_animation: bool,
+    paused: bool,
+    indicator_kind: Option<IndicatorKind>,
+    indicator_shape: Option<IndicatorShape>,
     indicator_style: Option<IndicatorStyle>,
     indicator_speed: Option<IndicatorSpeed>,
+    indicator_label: Option<IndicatorLabel>,
     indicator_size: f32,
     indicator_custom_speed: u64,
     indicator_custom_color_r: u8,
@@ -48,8 +53,13 @@ struct MyApp {
 enum MyMessage {
     ResetButton,
     ThemeToggler(bool),
+    AnimationToggler(bool),
+    PauseToggler(bool),
+    IndicatorKindPicker(IndicatorKind),
+    IndicatorShapePicker(IndicatorShape),
     IndicatorStylePicker(IndicatorStyle),
     IndicatorSpeedPicker(IndicatorSpeed),
+    IndicatorLabelPicker(IndicatorLabel),
     IndicatorSizeSlider(f32),
     IndicatorSpeedInput(u64),
     IndicatorColorInput(u8, Color),
@@ -59,8 +69,13 @@ impl Default for MyApp {
     fn default() -> Self {
         Self {
             dark_mode: true,
+            smooth_animation: false,
+            paused: false,
+            indicator_kind: Some(IndicatorKind::default()),
+            indicator_shape: Some(IndicatorShape::default()),
             indicator_style: Some(IndicatorStyle::default()),
             indicator_speed: Some(IndicatorSpeed::default()),
+            indicator_label: Some(IndicatorLabel::default()),
             indicator_size: INDICATOR_SIZE_DEFAULT,
             indicator_custom_speed: INDICATOR_CUSTOM_SPEED_DEFAULT,
             indicator_custom_color_r: INDICATOR_CUSTOM_COLOR_DEFAULT_R,
@@ -81,8 +96,13 @@ impl MyApp {
 
     fn reset(&mut self) {
         self.dark_mode = true;
+        self.smooth_animation = false;
+        self.paused = false;
+        self.indicator_kind = Some(IndicatorKind::default());
+        self.indicator_shape = Some(IndicatorShape::default());
         self.indicator_style = Some(IndicatorStyle::default());
         self.indicator_speed = Some(IndicatorSpeed::default());
+        self.indicator_label = Some(IndicatorLabel::default());
         self.indicator_size = INDICATOR_SIZE_DEFAULT;
         self.indicator_size = INDICATOR_SIZE_DEFAULT;
         self.indicator_custom_speed = INDICATOR_CUSTOM_SPEED_DEFAULT;
@@ -108,6 +128,81 @@ impl MyApp {
                 .align_items(iced::Alignment::Center)
         };
 
+        let animation_toggler = {
+            let label = iced::widget::text("Smooth Animation:");
+
+            let toggler =
+                iced::widget::toggler(None, self.smooth_animation, MyMessage::AnimationToggler)
+                    .width(iced::Length::Shrink)
+                    .text_alignment(iced::alignment::Horizontal::Center);
+
+            let spacer = iced::widget::horizontal_space(iced::Length::Fill);
+
+            iced::widget::row!(label, spacer, toggler)
+                .spacing(SPACING_SMALL)
+                .width(iced::Length::Fill)
+                .height(iced::Length::Shrink)
+                .align_items(iced::Alignment::Center)
+        };
+
+        let pause_toggler = {
+            let label = iced::widget::text("Paused:");
+
+            let toggler = iced::widget::toggler(None, self.paused, MyMessage::PauseToggler)
+                .width(iced::Length::Shrink)
+                .text_alignment(iced::alignment::Horizontal::Center);
+
+            let spacer = iced::widget::horizontal_space(iced::Length::Fill);
+
+            iced::widget::row!(label, spacer, toggler)
+                .spacing(SPACING_SMALL)
+                .width(iced::Length::Fill)
+                .height(iced::Length::Shrink)
+                .align_items(iced::Alignment::Center)
+        };
+
+        let kind_picker = {
+            let label = iced::widget::text("Kind:");
+
+            let picker = iced::widget::pick_list(
+                &IndicatorKind::ALL[..],
+                self.indicator_kind,
+                MyMessage::IndicatorKindPicker,
+            );
+
+            let spacer = iced::widget::horizontal_space(iced::Length::Fill);
+
+            let row = iced::widget::row!(label, spacer, picker)
+                .spacing(SPACING_SMALL)
+                .width(iced::Length::Fill)
+                .height(iced::Length::Shrink)
+                .align_items(iced::Alignment::Center);
+
+            let mut column = iced::widget::Column::new();
+            column = column.push(row);
+
+            if let Some(IndicatorKind::Dots) = self.indicator_kind {
+                let label = iced::widget::text("Shape:");
+
+                let picker = iced::widget::pick_list(
+                    &IndicatorShape::ALL[..],
+                    self.indicator_shape,
+                    MyMessage::IndicatorShapePicker,
+                );
+
+                let spacer = iced::widget::horizontal_space(iced::Length::Fill);
+
+                let row = iced::widget::row!(spacer, label, picker)
+                    .spacing(SPACING_NORMAL)
+                    .width(iced::Length::Fill)
+                    .align_items(iced::Alignment::Center);
+
+                column = column.push(row);
+            }
+
+            column.spacing(SPACING_NORMAL)
+        };
+
         let style_picker = {
             let label = iced::widget::text("Style:");
 
@@ -243,6 +338,24 @@ impl MyApp {
             column.spacing(SPACING_NORMAL)
         };
 
+        let label_picker = {
+            let label = iced::widget::text("Label:");
+
+            let picker = iced::widget::pick_list(
+                &IndicatorLabel::ALL[..],
+                self.indicator_label,
+                MyMessage::IndicatorLabelPicker,
+            );
+
+            let spacer = iced::widget::horizontal_space(iced::Length::Fill);
+
+            iced::widget::row!(label, spacer, picker)
+                .spacing(SPACING_SMALL)
+                .width(iced::Length::Fill)
+                .height(iced::Length::Shrink)
+                .align_items(iced::Alignment::Center)
+        };
+
         let reset_button = {
             let label = iced::widget::text("Reset")
                 .width(iced::Length::Fill)
@@ -256,8 +369,12 @@ impl MyApp {
 
         let column = iced::widget::column!(
             theme_toggler,
+            animation_toggler,
+            pause_toggler,
+            kind_picker,
             style_picker,
             speed_picker,
+            label_picker,
             bottom_spacer,
             reset_button
         )
@@ -306,20 +423,43 @@ impl MyApp {
     }
 
     fn view_content(&self) -> iced::Element<'_, MyMessage> {
-        let loading_indicator = iced_loading_indicator::Widget::new(
-            self.indicator_size,
-            Some(
-                self.indicator_style
-                    .unwrap_or(Default::default())
-                    .to_loading_indicator_style(Some(self.current_custom_color())),
-            ),
-            self.dark_mode,
+        let loading_indicator = iced_loading_indicator::Widget::with_size(
+            self.indicator_kind
+                .unwrap_or_default()
+                .to_loading_indicator_size(self.indicator_size),
         )
+        .style(
+            self.indicator_style
+                .unwrap_or_default()
+                .to_loading_indicator_style(Some(self.current_custom_color())),
+        )
+        .inactive(iced_loading_indicator::Inactive::Auto)
         .tick_duration_ms(
             self.indicator_speed
-                .unwrap_or(Default::default())
+                .unwrap_or_default()
                 .to_loading_indicator_speed(Some(self.indicator_custom_speed)),
+        )
+        .animation(if self.smooth_animation {
+            iced_loading_indicator::Animation::Smooth
+        } else {
+            iced_loading_indicator::Animation::Discrete
+        })
+        .paused(self.paused)
+        .kind(
+            self.indicator_kind
+                .unwrap_or_default()
+                .to_loading_indicator_kind(),
+        )
+        .shape(
+            self.indicator_shape
+                .unwrap_or_default()
+                .to_loading_indicator_shape(),
         );
+        let loading_indicator = match self.indicator_label.unwrap_or_default().to_placement() {
+            Some(placement) => loading_indicator
+                .label(iced_loading_indicator::Label::new("Loading…").placement(placement)),
+            None => loading_indicator,
+        };
 
         iced::widget::container(loading_indicator)
             .height(iced::Length::Fill)
@@ -388,6 +528,21 @@ impl iced::Application for MyApp {
             MyMessage::ThemeToggler(value) => {
                 self.dark_mode = value;
             }
+            MyMessage::AnimationToggler(value) => {
+                self.smooth_animation = value;
+            }
+            MyMessage::PauseToggler(value) => {
+                self.paused = value;
+            }
+            MyMessage::IndicatorKindPicker(value) => {
+                self.indicator_kind = Some(value);
+            }
+            MyMessage::IndicatorShapePicker(value) => {
+                self.indicator_shape = Some(value);
+            }
+            MyMessage::IndicatorLabelPicker(value) => {
+                self.indicator_label = Some(value);
+            }
             MyMessage::IndicatorStylePicker(value) => {
                 self.indicator_style = Some(value);
                 if let IndicatorStyle::CustomColor = value {
@@ -407,24 +562,39 @@ impl iced::Application for MyApp {
     }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 enum IndicatorStyle {
     #[default]
     TextColor,
     PrimaryColor,
+    SuccessColor,
+    DangerColor,
     CustomColor,
 }
 
 impl IndicatorStyle {
-    const ALL: [Self; 3] = [Self::TextColor, Self::PrimaryColor, Self::CustomColor];
+    const ALL: [Self; 5] = [
+        Self::TextColor,
+        Self::PrimaryColor,
+        Self::SuccessColor,
+        Self::DangerColor,
+        Self::CustomColor,
+    ];
 
     fn to_loading_indicator_style(
-        &self,
+        self,
         custom_color: Option<iced::Color>,
     ) -> iced_loading_indicator::Style {
         match self {
             Self::TextColor => iced_loading_indicator::Style::TextColor,
             Self::PrimaryColor => iced_loading_indicator::Style::PrimaryColor,
+            Self::SuccessColor => {
+                iced_loading_indicator::Style::Success(iced_loading_indicator::Strength::Base)
+            }
+            Self::DangerColor => {
+                iced_loading_indicator::Style::Danger(iced_loading_indicator::Strength::Base)
+            }
             Self::CustomColor => iced_loading_indicator::Style::CustomColor(
                 custom_color.unwrap_or(iced::Color::from_rgb8(0xaa, 0x11, 0xff)),
             ),
@@ -440,11 +610,145 @@ impl std::fmt::Display for IndicatorStyle {
             match self {
                 Self::TextColor => "Text Color",
                 Self::PrimaryColor => "Primary Color",
+                Self::SuccessColor => "Success Color",
+                Self::DangerColor => "Danger Color",
                 Self::CustomColor => "Custom Color",
             }
         )
     }
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum IndicatorKind {
+    #[default]
+    Dots,
+    Arc,
+    Bar,
+    Typing,
+    Ripple,
+}
+
+impl IndicatorKind {
+    const ALL: [Self; 5] = [Self::Dots, Self::Arc, Self::Bar, Self::Typing, Self::Ripple];
+
+    fn to_loading_indicator_kind(self) -> iced_loading_indicator::Kind {
+        match self {
+            Self::Dots => iced_loading_indicator::Kind::Dots,
+            Self::Arc => iced_loading_indicator::Kind::Arc,
+            Self::Bar => iced_loading_indicator::Kind::Bar,
+            Self::Typing => iced_loading_indicator::Kind::Typing,
+            Self::Ripple => iced_loading_indicator::Kind::Ripple,
+        }
+    }
+
+    // The typing indicator is three times as wide as it is high,
+    // hence the smaller size to make it fit.
+    fn to_loading_indicator_size(self, size: f32) -> f32 {
+        match self {
+            Self::Typing => size / 3.0,
+            _ => size,
+        }
+    }
+}
+
+impl std::fmt::Display for IndicatorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Dots => "Dots",
+                Self::Arc => "Arc",
+                Self::Bar => "Bar",
+                Self::Typing => "Typing",
+                Self::Ripple => "Ripple",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum IndicatorShape {
+    #[default]
+    Circle,
+    Bar,
+    Square,
+}
+
+impl IndicatorShape {
+    const ALL: [Self; 3] = [Self::Circle, Self::Bar, Self::Square];
+
+    fn to_loading_indicator_shape(self) -> iced_loading_indicator::Shape {
+        match self {
+            Self::Circle => iced_loading_indicator::Shape::Circle,
+            Self::Bar => iced_loading_indicator::Shape::Bar {
+                inner: 0.5,
+                outer: 1.0,
+            },
+            Self::Square => iced_loading_indicator::Shape::Square,
+        }
+    }
+}
+
+impl std::fmt::Display for IndicatorShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Circle => "Circle",
+                Self::Bar => "Bar",
+                Self::Square => "Square",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum IndicatorLabel {
+    #[default]
+    None,
+    Below,
+    Above,
+    Left,
+    Right,
+}
+
+impl IndicatorLabel {
+    const ALL: [Self; 5] = [
+        Self::None,
+        Self::Below,
+        Self::Above,
+        Self::Left,
+        Self::Right,
+    ];
+
+    fn to_placement(self) -> Option<iced_loading_indicator::Placement> {
+        match self {
+            Self::None => None,
+            Self::Below => Some(iced_loading_indicator::Placement::Below),
+            Self::Above => Some(iced_loading_indicator::Placement::Above),
+            Self::Left => Some(iced_loading_indicator::Placement::Left),
+            Self::Right => Some(iced_loading_indicator::Placement::Right),
+        }
+    }
+}
+
+impl std::fmt::Display for IndicatorLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "None",
+                Self::Below => "Below",
+                Self::Above => "Above",
+                Self::Left => "Left",
+                Self::Right => "Right",
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 enum IndicatorSpeed {
     Fast,
@@ -462,7 +766,7 @@ impl IndicatorSpeed {
     const SLOW: u64 = 200;
     const CUSTOM_DEFAULT: u64 = 500;
 
-    fn to_loading_indicator_speed(&self, custom_speed: Option<u64>) -> u64 {
+    fn to_loading_indicator_speed(self, custom_speed: Option<u64>) -> u64 {
         match self {
             Self::Fast => Self::FAST,
             Self::Normal => Self::NORMAL,
@@ -481,7 +785,7 @@ impl std::fmt::Display for IndicatorSpeed {
                 Self::Fast => format!("Fast ({} ms)", Self::FAST),
                 Self::Normal => format!("Normal ({} ms)", Self::NORMAL),
                 Self::Slow => format!("Slow ({} ms)", Self::SLOW),
-                Self::Custom => format!("Custom"),
+                Self::Custom => "Custom".to_string(),
             }
         )
     }
@@ -599,7 +903,7 @@ mod numeric_input {
             .on_input(Event::InputChanged)
             .width(iced::Length::Fixed(50.0))
             .padding([3.0, 4.0])
-            .font(self.font.unwrap_or(Default::default()))
+            .font(self.font.unwrap_or_default())
             .size(self.size.unwrap_or(16.into()));
 
             if let Some(id) = self.id.as_ref() {
@@ -14,7 +14,16 @@ fn main() -> iced::Result {
     })
 }
 
-#[derive(Debug)]
+/// The loading indicator's settings, which could just as well be used
+/// to build a self-animated widget (see [`iced_loading_indicator::Config::widget`]).
+fn config() -> iced_loading_indicator::Config {
+    iced_loading_indicator::Config::new()
+        .size(LOADING_INDICATOR_SIZE)
+        .style(iced_loading_indicator::Style::PrimaryColor)
+        .tick_duration_ms(LOADING_INDICATOR_SPEED_MS)
+}
+
+#[derive(Debug, Default)]
 struct MyApp {
     index: iced_loading_indicator::Index,
 }
@@ -24,14 +33,6 @@ enum MyMessage {
     Tick,
 }
 
-impl Default for MyApp {
-    fn default() -> Self {
-        Self {
-            index: Default::default(),
-        }
-    }
-}
-
 impl iced::Application for MyApp {
     type Executor = iced::executor::Default;
     type Flags = ();
@@ -51,14 +52,11 @@ impl iced::Application for MyApp {
     }
 
     fn subscription(&self) -> iced::Subscription<Self::Message> {
-        iced::time::every(std::time::Duration::from_millis(LOADING_INDICATOR_SPEED_MS))
-            .map(|_| MyMessage::Tick)
+        iced::time::every(config().tick_duration()).map(|_| MyMessage::Tick)
     }
 
     fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
-        let canvas =
-            iced_loading_indicator::LoadingIndicator::new(LOADING_INDICATOR_SIZE, self.index)
-                .style(iced_loading_indicator::Style::PrimaryColor);
+        let canvas = config().loading_indicator(self.index);
 
         iced::widget::container(
             iced::widget::column!(canvas)
@@ -0,0 +1,75 @@
+use iced::Application as _;
+
+const NUMBER_OF_ROWS: usize = 200;
+const LOADING_INDICATOR_SIZE: f32 = 20.0;
+const UNFOCUSED_THROTTLE_MS: u64 = 500;
+
+fn main() -> iced::Result {
+    MyApp::run(iced::Settings {
+        window: iced::window::Settings {
+            size: (400, 400),
+            position: iced::window::Position::Specific(50, 800),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Default)]
+struct MyApp {}
+
+#[derive(Debug, Clone)]
+enum MyMessage {}
+
+impl iced::Application for MyApp {
+    type Executor = iced::executor::Default;
+    type Flags = ();
+    type Message = MyMessage;
+    type Theme = iced::theme::Theme;
+
+    fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
+        (Default::default(), iced::Command::none())
+    }
+
+    fn title(&self) -> String {
+        "Loading Indicator List Demo".into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        iced::theme::Theme::Dark
+    }
+
+    fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
+        // Only the rows currently in view request redraws, while the others
+        // catch up with the elapsed time once scrolled back into view. While the
+        // window is unfocused, the rows get redrawn a couple of times per second.
+        // Being synchronized, all the rows spin in unison.
+        let rows = (0..NUMBER_OF_ROWS).map(|index| {
+            iced::widget::row!(
+                iced_loading_indicator::Widget::with_size(LOADING_INDICATOR_SIZE)
+                    .style(iced_loading_indicator::Style::PrimaryColor)
+                    .synchronized(true)
+                    .unfocused(iced_loading_indicator::Unfocused::Throttle(
+                        std::time::Duration::from_millis(UNFOCUSED_THROTTLE_MS),
+                    )),
+                iced::widget::text(format!("Item {}", index + 1))
+            )
+            .spacing(10)
+            .align_items(iced::Alignment::Center)
+            .into()
+        });
+
+        iced::widget::scrollable(
+            iced::widget::Column::with_children(rows.collect())
+                .width(iced::Length::Fill)
+                .spacing(10)
+                .padding(20),
+        )
+        .height(iced::Length::Fill)
+        .into()
+    }
+
+    fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
+        match message {}
+    }
+}
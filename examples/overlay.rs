@@ -0,0 +1,102 @@
+use iced::Application as _;
+
+const LOADING_DURATION_MS: u64 = 2000;
+const LABEL_ROTATION_MS: u64 = 800;
+
+fn main() -> iced::Result {
+    MyApp::run(iced::Settings {
+        window: iced::window::Settings {
+            size: (400, 300),
+            position: iced::window::Position::Specific(50, 800),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Default)]
+struct MyApp {
+    name: String,
+    is_loading: bool,
+}
+
+#[derive(Debug, Clone)]
+enum MyMessage {
+    NameInput(String),
+    SubmitButton,
+    Submitted,
+}
+
+impl iced::Application for MyApp {
+    type Executor = iced::executor::Default;
+    type Flags = ();
+    type Message = MyMessage;
+    type Theme = iced::theme::Theme;
+
+    fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
+        (Default::default(), iced::Command::none())
+    }
+
+    fn title(&self) -> String {
+        "Loading Overlay Demo".into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        iced::theme::Theme::Dark
+    }
+
+    fn subscription(&self) -> iced::Subscription<Self::Message> {
+        // Simulates some work taking a while to complete.
+        if self.is_loading {
+            iced::time::every(std::time::Duration::from_millis(LOADING_DURATION_MS))
+                .map(|_| MyMessage::Submitted)
+        } else {
+            iced::Subscription::none()
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
+        let form = iced::widget::column!(
+            iced::widget::text_input("Name", &self.name).on_input(MyMessage::NameInput),
+            iced::widget::button("Submit").on_press(MyMessage::SubmitButton)
+        )
+        .spacing(10)
+        .padding(20);
+
+        let overlay = iced_loading_indicator::LoadingOverlay::new(form, self.is_loading).indicator(
+            iced_loading_indicator::Widget::with_size(60.0)
+                .style(iced_loading_indicator::Style::PrimaryColor)
+                .label(
+                    iced_loading_indicator::Label::rotating(["Connecting…", "Submitting…"])
+                        .rotation(iced_loading_indicator::Rotation::Interval(
+                            std::time::Duration::from_millis(LABEL_ROTATION_MS),
+                        )),
+                ),
+        );
+
+        iced::widget::container(overlay)
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill)
+            .center_x()
+            .center_y()
+            .padding(20)
+            .into()
+    }
+
+    fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
+        match message {
+            MyMessage::NameInput(value) => {
+                self.name = value;
+                iced::Command::none()
+            }
+            MyMessage::SubmitButton => {
+                self.is_loading = true;
+                iced::Command::none()
+            }
+            MyMessage::Submitted => {
+                self.is_loading = false;
+                iced::Command::none()
+            }
+        }
+    }
+}
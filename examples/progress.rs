@@ -0,0 +1,102 @@
+use iced::Application as _;
+
+const LOADING_INDICATOR_SIZE: f32 = 150.0;
+const LOADING_INDICATOR_BAR_HEIGHT: f32 = 4.0;
+const PROGRESS_STEP: f32 = 0.07;
+const PROGRESS_INTERVAL_MS: u64 = 600;
+
+fn main() -> iced::Result {
+    MyApp::run(iced::Settings {
+        window: iced::window::Settings {
+            size: (500, 300),
+            position: iced::window::Position::Specific(50, 800),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Default)]
+struct MyApp {
+    progress: f32,
+}
+
+#[derive(Debug, Clone)]
+enum MyMessage {
+    Tick,
+}
+
+impl iced::Application for MyApp {
+    type Executor = iced::executor::Default;
+    type Flags = ();
+    type Message = MyMessage;
+    type Theme = iced::theme::Theme;
+
+    fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
+        (Default::default(), iced::Command::none())
+    }
+
+    fn title(&self) -> String {
+        "Loading Indicator Progress Demo".into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        iced::theme::Theme::Dark
+    }
+
+    fn subscription(&self) -> iced::Subscription<Self::Message> {
+        iced::time::every(std::time::Duration::from_millis(PROGRESS_INTERVAL_MS))
+            .map(|_| MyMessage::Tick)
+    }
+
+    fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
+        // The widget eases towards each new value...
+        let widget = iced_loading_indicator::Widget::with_size(LOADING_INDICATOR_SIZE)
+            .style(iced_loading_indicator::Style::PrimaryColor)
+            .inactive(iced_loading_indicator::Inactive::Auto)
+            .animation(iced_loading_indicator::Animation::Smooth)
+            .progress(self.progress);
+
+        // ... while the bare loading indicator jumps to it.
+        let canvas = iced_loading_indicator::LoadingIndicator::with_size(LOADING_INDICATOR_SIZE)
+            .inactive(iced_loading_indicator::Inactive::Auto)
+            .progress(self.progress);
+
+        // The bar kind fills the available width.
+        let bar = iced_loading_indicator::Widget::with_size(LOADING_INDICATOR_BAR_HEIGHT)
+            .kind(iced_loading_indicator::Kind::Bar)
+            .style(iced_loading_indicator::Style::PrimaryColor)
+            .animation(iced_loading_indicator::Animation::Smooth)
+            .progress(self.progress);
+
+        iced::widget::container(
+            iced::widget::column!(
+                iced::widget::row!(widget, canvas).spacing(40),
+                bar,
+                iced::widget::text(format!("{:.0} %", self.progress * 100.0))
+            )
+            .width(iced::Length::Fill)
+            .align_items(iced::Alignment::Center)
+            .spacing(20),
+        )
+        .width(iced::Length::Fill)
+        .height(iced::Length::Fill)
+        .center_x()
+        .center_y()
+        .padding(20)
+        .into()
+    }
+
+    fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
+        match message {
+            MyMessage::Tick => {
+                self.progress = if self.progress >= 1.0 {
+                    0.0
+                } else {
+                    (self.progress + PROGRESS_STEP).min(1.0)
+                };
+                iced::Command::none()
+            }
+        }
+    }
+}
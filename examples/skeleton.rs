@@ -0,0 +1,71 @@
+use iced::Application as _;
+
+const LINE_HEIGHT: f32 = 12.0;
+const LINE_SPACING: f32 = 8.0;
+const LINE_WIDTHS: [f32; 3] = [300.0, 300.0, 180.0];
+
+fn main() -> iced::Result {
+    MyApp::run(iced::Settings {
+        window: iced::window::Settings {
+            size: (400, 300),
+            position: iced::window::Position::Specific(50, 800),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+#[derive(Debug, Default)]
+struct MyApp {}
+
+#[derive(Debug, Clone)]
+enum MyMessage {}
+
+impl iced::Application for MyApp {
+    type Executor = iced::executor::Default;
+    type Flags = ();
+    type Message = MyMessage;
+    type Theme = iced::theme::Theme;
+
+    fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
+        (Default::default(), iced::Command::none())
+    }
+
+    fn title(&self) -> String {
+        "Skeleton Demo".into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        iced::theme::Theme::Dark
+    }
+
+    fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
+        // A block standing for an image, which fills the available width...
+        let block = iced_loading_indicator::Skeleton::new()
+            .height(100.0)
+            .inactive(iced_loading_indicator::Inactive::Auto);
+
+        // ... followed by a few lines of text.
+        let lines = iced_loading_indicator::Skeleton::with_shapes(
+            LINE_WIDTHS.iter().enumerate().map(|(i, width)| {
+                iced::Rectangle::new(
+                    iced::Point::new(0.0, (LINE_HEIGHT + LINE_SPACING) * i as f32),
+                    iced::Size::new(*width, LINE_HEIGHT),
+                )
+            }),
+        )
+        .border_radius(LINE_HEIGHT / 2.0)
+        .inactive(iced_loading_indicator::Inactive::Auto);
+
+        iced::widget::container(iced::widget::column!(block, lines).spacing(20))
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill)
+            .center_y()
+            .padding(40)
+            .into()
+    }
+
+    fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
+        match message {}
+    }
+}
@@ -14,18 +14,12 @@ fn main() -> iced::Result {
     })
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct MyApp {}
 
 #[derive(Debug, Clone)]
 enum MyMessage {}
 
-impl Default for MyApp {
-    fn default() -> Self {
-        Self {}
-    }
-}
-
 impl iced::Application for MyApp {
     type Executor = iced::executor::Default;
     type Flags = ();
@@ -45,14 +39,12 @@ impl iced::Application for MyApp {
     }
 
     fn view(&self) -> iced::Element<'_, Self::Message, iced::Renderer<Self::Theme>> {
-        let widget = iced_loading_indicator::Widget::new(
-            LOADING_INDICATOR_SIZE,
-            Some(iced_loading_indicator::Style::CustomColor(
+        let widget = iced_loading_indicator::Widget::with_size(LOADING_INDICATOR_SIZE)
+            .style(iced_loading_indicator::Style::CustomColor(
                 iced::Color::from_rgb8(0xaa, 0xaa, 0xff),
-            )),
-            true,
-        )
-        .tick_duration_ms(LOADING_INDICATOR_SPEED_MS);
+            ))
+            .lighter_inactive(true)
+            .tick_duration_ms(LOADING_INDICATOR_SPEED_MS);
 
         iced::widget::container(
             iced::widget::column!(widget)
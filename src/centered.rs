@@ -0,0 +1,97 @@
+//! Helpers shared by the widgets that lay out some content and center
+//! a loading indicator within it (i.e. [`crate::LoadingButton`] and
+//! [`crate::LoadingOverlay`]).
+//!
+//! The widgets' trees and layout nodes both hold two children: the content's
+//! first, followed by the indicator's.
+
+/// Returns the widget trees of the `content` and of the `indicator`.
+pub(crate) fn children<M, Renderer>(
+    content: &iced_widget::core::Element<'_, M, Renderer>,
+    indicator: &iced_widget::core::Element<'_, M, Renderer>,
+) -> Vec<iced_widget::core::widget::Tree>
+where
+    Renderer: iced_widget::core::Renderer,
+{
+    vec![
+        iced_widget::core::widget::Tree::new(content),
+        iced_widget::core::widget::Tree::new(indicator),
+    ]
+}
+
+/// Diffs the children of the `tree` with the `content` and the `indicator`.
+pub(crate) fn diff<M, Renderer>(
+    tree: &mut iced_widget::core::widget::Tree,
+    content: &iced_widget::core::Element<'_, M, Renderer>,
+    indicator: &iced_widget::core::Element<'_, M, Renderer>,
+) where
+    Renderer: iced_widget::core::Renderer,
+{
+    tree.diff_children(&[content, indicator]);
+}
+
+/// Lays out the `content` within the `limits`, along with the `indicator`, which
+/// gets constrained to the content's size and centered within it.
+pub(crate) fn layout<M, Renderer>(
+    content: &iced_widget::core::Element<'_, M, Renderer>,
+    indicator: &iced_widget::core::Element<'_, M, Renderer>,
+    renderer: &Renderer,
+    limits: &iced_widget::core::layout::Limits,
+) -> iced_widget::core::layout::Node
+where
+    Renderer: iced_widget::core::Renderer,
+{
+    let content = content.as_widget().layout(renderer, limits);
+    let size = content.size();
+
+    let mut indicator = indicator.as_widget().layout(
+        renderer,
+        &iced_widget::core::layout::Limits::new(iced_widget::core::Size::ZERO, size),
+    );
+    let indicator_size = indicator.size();
+    indicator.move_to(iced_widget::core::Point::new(
+        (size.width - indicator_size.width) / 2.0,
+        (size.height - indicator_size.height) / 2.0,
+    ));
+
+    iced_widget::core::layout::Node::with_children(size, vec![content, indicator])
+}
+
+/// Forwards the `operation` to the `content`.
+pub(crate) fn operate<M, Renderer>(
+    content: &iced_widget::core::Element<'_, M, Renderer>,
+    tree: &mut iced_widget::core::widget::Tree,
+    layout: iced_widget::core::Layout<'_>,
+    renderer: &Renderer,
+    operation: &mut dyn iced_widget::core::widget::Operation<M>,
+) where
+    Renderer: iced_widget::core::Renderer,
+{
+    content.as_widget().operate(
+        &mut tree.children[0],
+        layout.children().next().unwrap(),
+        renderer,
+        operation,
+    );
+}
+
+/// Returns the `content`'s overlay, if any, unless `is_loading` is `true`.
+pub(crate) fn overlay<'a, 'b, M, Renderer>(
+    content: &'b mut iced_widget::core::Element<'a, M, Renderer>,
+    is_loading: bool,
+    tree: &'b mut iced_widget::core::widget::Tree,
+    layout: iced_widget::core::Layout<'_>,
+    renderer: &Renderer,
+) -> Option<iced_widget::core::overlay::Element<'b, M, Renderer>>
+where
+    Renderer: iced_widget::core::Renderer,
+{
+    if is_loading {
+        return None;
+    }
+    content.as_widget_mut().overlay(
+        &mut tree.children[0],
+        layout.children().next().unwrap(),
+        renderer,
+    )
+}
@@ -0,0 +1,262 @@
+use crate::{
+    Animation, Inactive, Index, Kind, LoadingIndicator, Shape, StyleSheet, Trail, Unfocused, Widget,
+};
+
+/// A set of loading indicator settings that can be used to build either a
+/// [`LoadingIndicator`] (i.e. when the application takes care of the animation itself)
+/// or a [`Widget`] (i.e. when the animation is left to the widget), so that
+/// the same settings can drive both.
+///
+/// ## Example
+/// ```
+/// let config = iced_loading_indicator::Config::new()
+///     .size(40.0)
+///     .style(iced_loading_indicator::Style::PrimaryColor)
+///     .number_of_circles(8);
+///
+/// let widget: iced_loading_indicator::Widget<'_, ()> = config.widget();
+/// let indicator = config.loading_indicator(Default::default());
+/// ```
+pub struct Config<Theme = iced_style::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The loading indicator's size (in pixels).
+    pub(crate) size: f32,
+    /// The loading indicator's style.
+    pub(crate) style: Theme::Style,
+    /// The configuration of the trail following the active circle.
+    pub(crate) trail: Trail,
+    /// The number of circles from which the loading indicator is made up.
+    pub(crate) number_of_circles: usize,
+    /// The "tick interval" (i.e. animation speed), in milliseconds.
+    pub(crate) tick_duration_ms: u64,
+    /// The way in which the loading indicator gets animated.
+    pub(crate) animation: Animation,
+    /// The way in which the widget gets animated while its window is unfocused.
+    pub(crate) unfocused: Unfocused,
+    /// Whether the widget's animation derives from an epoch shared with other widgets.
+    pub(crate) synchronized: bool,
+    /// The kind of loading indicator to be drawn.
+    pub(crate) kind: Kind,
+    /// The shape of the circles of a [`Kind::Dots`] loading indicator.
+    pub(crate) shape: Shape,
+}
+
+impl<Theme> Default for Config<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self {
+            size: crate::DEFAULT_SIZE,
+            style: Default::default(),
+            trail: Default::default(),
+            number_of_circles: crate::DEFAULT_NUMBER_OF_CIRCLES,
+            tick_duration_ms: crate::DEFAULT_TICK_DURATION_MS,
+            animation: Default::default(),
+            unfocused: Default::default(),
+            synchronized: false,
+            kind: Default::default(),
+            shape: Default::default(),
+        }
+    }
+}
+
+impl<Theme> Clone for Config<Theme>
+where
+    Theme: StyleSheet,
+{
+    fn clone(&self) -> Self {
+        Self {
+            size: self.size,
+            style: self.style.clone(),
+            trail: self.trail.clone(),
+            number_of_circles: self.number_of_circles,
+            tick_duration_ms: self.tick_duration_ms,
+            animation: self.animation,
+            unfocused: self.unfocused,
+            synchronized: self.synchronized,
+            kind: self.kind,
+            shape: self.shape.clone(),
+        }
+    }
+}
+
+impl<Theme> std::fmt::Debug for Config<Theme>
+where
+    Theme: StyleSheet,
+    Theme::Style: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("size", &self.size)
+            .field("style", &self.style)
+            .field("trail", &self.trail)
+            .field("number_of_circles", &self.number_of_circles)
+            .field("tick_duration_ms", &self.tick_duration_ms)
+            .field("animation", &self.animation)
+            .field("unfocused", &self.unfocused)
+            .field("synchronized", &self.synchronized)
+            .field("kind", &self.kind)
+            .field("shape", &self.shape)
+            .finish()
+    }
+}
+
+impl<Theme> Config<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// A factory method that returns the default [`Config`], i.e. using [`crate::DEFAULT_SIZE`],
+    /// the theme's default style, the default [`Trail`], [`crate::DEFAULT_NUMBER_OF_CIRCLES`],
+    /// [`crate::DEFAULT_TICK_DURATION_MS`], [`Animation::Discrete`] and [`Kind::Dots`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A setter method that can be used to specify the loading indicator's size (in pixels),
+    /// which is the height of a [`Kind::Bar`] indicator.
+    pub fn size(self, value: f32) -> Self {
+        Self {
+            size: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the style (e.g. [`crate::Style`]
+    /// for the built-in theme).
+    pub fn style(self, value: impl Into<Theme::Style>) -> Self {
+        Self {
+            style: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the [`Trail`] following
+    /// the active circle.
+    pub fn trail(self, value: Trail) -> Self {
+        Self {
+            trail: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify whether a lighter alpha
+    /// channel value should be used for the inactive circles (see [`Trail::lighter_inactive`]).
+    pub fn lighter_inactive(self, value: bool) -> Self {
+        Self {
+            trail: self.trail.lighter_inactive(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify how the alpha channel of
+    /// the inactive circles gets determined (see [`Trail::inactive`]).
+    pub fn inactive(self, value: Inactive) -> Self {
+        Self {
+            trail: self.trail.inactive(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the number of circles from
+    /// which the loading indicator is made up.
+    ///
+    /// **Panics** if `value` is zero.
+    pub fn number_of_circles(self, value: usize) -> Self {
+        assert!(value > 0, "the number of circles must be positive");
+        Self {
+            number_of_circles: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the animation speed (in milliseconds).
+    pub fn tick_duration_ms(self, value: u64) -> Self {
+        Self {
+            tick_duration_ms: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the animation speed as the
+    /// duration of a single tick (i.e. the time it takes the active circle to move
+    /// by one step). Sub-millisecond precision is truncated.
+    pub fn speed(self, value: std::time::Duration) -> Self {
+        self.tick_duration_ms(value.as_millis() as u64)
+    }
+
+    /// A setter method that can be used to specify the [`Animation`] mode.
+    pub fn animation(self, value: Animation) -> Self {
+        Self {
+            animation: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify how a [`Widget`] gets animated
+    /// while its window is unfocused (see [`Unfocused`]).
+    pub fn unfocused(self, value: Unfocused) -> Self {
+        Self {
+            unfocused: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify whether a [`Widget`]'s animation
+    /// derives from an epoch shared by all synchronized widgets (see [`Widget::synchronized`]).
+    pub fn synchronized(self, value: bool) -> Self {
+        Self {
+            synchronized: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the [`Kind`] of loading indicator.
+    pub fn kind(self, value: Kind) -> Self {
+        Self {
+            kind: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the [`Shape`] of the circles
+    /// of a [`Kind::Dots`] loading indicator.
+    pub fn shape(self, value: Shape) -> Self {
+        Self {
+            shape: value,
+            ..self
+        }
+    }
+
+    /// Returns the duration of a single tick, which can be used to drive the
+    /// animation of a [`LoadingIndicator`] from inside the application (e.g. with
+    /// `iced::time::every`).
+    pub fn tick_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.tick_duration_ms)
+    }
+
+    /// Returns an [`Index`] matching the configured number of circles.
+    pub fn index(&self) -> Index {
+        Index::with_number_of_circles(self.number_of_circles)
+    }
+
+    /// Builds a [`LoadingIndicator`] drawn at the provided `index`.
+    pub fn loading_indicator(&self, index: Index) -> LoadingIndicator<Theme> {
+        LoadingIndicator::new(self.size, index)
+            .number_of_circles(self.number_of_circles)
+            .style(self.style.clone())
+            .trail(self.trail.clone())
+            .kind(self.kind)
+            .shape(self.shape.clone())
+    }
+
+    /// Builds a [`Widget`] that animates itself according to this configuration.
+    pub fn widget<'a, M, Renderer>(&self) -> Widget<'a, M, Renderer>
+    where
+        Renderer: iced_widget::core::Renderer<Theme = Theme>,
+    {
+        Widget::from_config(self.clone())
+    }
+}
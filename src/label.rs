@@ -0,0 +1,362 @@
+//! Configure the caption shown next to the loading indicator.
+
+/// The spacing (in pixels) used by default between the loading indicator and its [`Label`].
+pub const DEFAULT_LABEL_SPACING: f32 = 8.0;
+
+/// The duration (in milliseconds) for which each message of a rotating [`Label`]
+/// gets shown by default (see [`Rotation::Interval`]).
+pub const DEFAULT_ROTATION_INTERVAL_MS: u64 = 2500;
+
+/// The duration (in milliseconds) of the cross-fade between two messages
+/// of a rotating [`Label`] used by default (see [`Label::cross_fade`]).
+pub const DEFAULT_CROSS_FADE_MS: u64 = 300;
+
+/// The placement of a [`Label`] relative to the loading indicator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Placement {
+    /// Below the indicator, centered horizontally.
+    #[default]
+    Below,
+    /// Above the indicator, centered horizontally.
+    Above,
+    /// On the left of the indicator, centered vertically.
+    Left,
+    /// On the right of the indicator, centered vertically.
+    Right,
+}
+
+/// The moment at which a rotating [`Label`] moves on to its next message.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rotation {
+    /// After the provided duration.
+    Interval(std::time::Duration),
+    /// After the provided number of full revolutions of the indicator (i.e.
+    /// as many ticks as there are circles, see [`crate::Config::tick_duration_ms`]).
+    Revolutions(u32),
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::Interval(std::time::Duration::from_millis(
+            DEFAULT_ROTATION_INTERVAL_MS,
+        ))
+    }
+}
+
+/// A caption (e.g. "Loading…" or "Syncing 3 files") shown next to the
+/// loading indicator [`crate::Widget`] (see [`crate::Widget::label`]).
+///
+/// Unless specified, the text gets drawn with the renderer's default text size,
+/// in the same color as the indicator's active circle (i.e. the [`crate::Appearance`]'s
+/// `color`, which follows the indicator's style).
+///
+/// A label can also cycle through several messages (see [`Label::rotating`]), e.g. for
+/// long operations, cross-fading from one to the next, in which case the widget takes
+/// care of the timing and gets laid out according to the largest message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Label {
+    /// The messages to be shown, in order.
+    messages: Vec<String>,
+    /// The moment at which the next message gets shown.
+    rotation: Rotation,
+    /// The duration of the cross-fade between two messages.
+    cross_fade: std::time::Duration,
+    /// The placement of the text relative to the indicator.
+    placement: Placement,
+    /// The spacing between the indicator and the text (in pixels).
+    spacing: f32,
+    /// The text size, if other than the renderer's default.
+    size: Option<f32>,
+    /// The text color, if other than the indicator's active color.
+    color: Option<iced_widget::core::Color>,
+}
+
+impl Label {
+    /// A factory method that returns a label showing the provided `content`
+    /// below the indicator, with the default settings otherwise.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self::rotating([content])
+    }
+
+    /// A factory method that returns a label cycling through the provided `messages`
+    /// (e.g. "Connecting…", "Downloading…" and "Almost there…"), every
+    /// [`DEFAULT_ROTATION_INTERVAL_MS`] milliseconds by default (see [`Self::rotation`]).
+    pub fn rotating(messages: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            messages: messages.into_iter().map(Into::into).collect(),
+            rotation: Default::default(),
+            cross_fade: std::time::Duration::from_millis(DEFAULT_CROSS_FADE_MS),
+            placement: Default::default(),
+            spacing: DEFAULT_LABEL_SPACING,
+            size: None,
+            color: None,
+        }
+    }
+
+    /// A setter method that can be used to specify where the text gets placed
+    /// relative to the indicator.
+    pub fn placement(self, value: Placement) -> Self {
+        Self {
+            placement: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the spacing (in pixels)
+    /// between the indicator and the text.
+    pub fn spacing(self, value: f32) -> Self {
+        Self {
+            spacing: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the text size (in pixels).
+    pub fn size(self, value: f32) -> Self {
+        Self {
+            size: Some(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the text color, instead
+    /// of the indicator's active color.
+    pub fn color(self, value: iced_widget::core::Color) -> Self {
+        Self {
+            color: Some(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify when a rotating label
+    /// moves on to its next message.
+    pub fn rotation(self, value: Rotation) -> Self {
+        Self {
+            rotation: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the duration of the cross-fade
+    /// between two messages of a rotating label, which gets capped to the
+    /// duration for which each message is shown.
+    pub fn cross_fade(self, value: std::time::Duration) -> Self {
+        Self {
+            cross_fade: value,
+            ..self
+        }
+    }
+
+    /// Returns the duration for which each message gets shown, given the duration
+    /// of one of the indicator's `revolution`s, if the label has several messages.
+    fn interval(&self, revolution: std::time::Duration) -> Option<std::time::Duration> {
+        if self.messages.len() < 2 {
+            return None;
+        }
+        let interval = match self.rotation {
+            Rotation::Interval(interval) => interval,
+            Rotation::Revolutions(revolutions) => revolution * revolutions,
+        };
+        (!interval.is_zero()).then_some(interval)
+    }
+
+    /// Returns the index of the message shown after `time` has elapsed, along with
+    /// the opacity of the next message, which is only above zero while cross-fading.
+    fn messages_at(
+        &self,
+        time: std::time::Duration,
+        revolution: std::time::Duration,
+    ) -> (usize, f32) {
+        let Some(interval) = self.interval(revolution) else {
+            return (0, 0.0);
+        };
+        let (interval, time) = (interval.as_secs_f32(), time.as_secs_f32());
+        let cross_fade = self.cross_fade.as_secs_f32().min(interval);
+        let index = (time / interval) as usize % self.messages.len();
+        // The cross-fade happens at the end of each interval.
+        let remaining = interval - time % interval;
+        let fade = if cross_fade > 0.0 && remaining < cross_fade {
+            1.0 - remaining / cross_fade
+        } else {
+            0.0
+        };
+        (index, fade)
+    }
+
+    /// Returns how long to wait after `time` has elapsed before the label needs to be
+    /// redrawn, being zero while cross-fading, or `None` if the label never changes.
+    pub(crate) fn next_redraw(
+        &self,
+        time: std::time::Duration,
+        revolution: std::time::Duration,
+    ) -> Option<std::time::Duration> {
+        let interval = self.interval(revolution)?;
+        let cross_fade = self.cross_fade.min(interval);
+        let remaining = interval
+            - std::time::Duration::from_nanos((time.as_nanos() % interval.as_nanos()) as u64);
+        Some(remaining.saturating_sub(cross_fade))
+    }
+
+    /// Returns whether the text and the indicator are laid out side by side.
+    fn is_horizontal(&self) -> bool {
+        matches!(self.placement, Placement::Left | Placement::Right)
+    }
+
+    /// Lays out the text within the provided `limits` next to the indicator, which gets
+    /// laid out by `layout_indicator` within what the text leaves available. The returned
+    /// node's children are the indicator's node, followed by the text's.
+    pub(crate) fn layout<Renderer>(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_widget::core::layout::Limits,
+        layout_indicator: impl FnOnce(
+            &iced_widget::core::layout::Limits,
+        ) -> iced_widget::core::layout::Node,
+    ) -> iced_widget::core::layout::Node
+    where
+        Renderer: iced_widget::core::text::Renderer,
+    {
+        // The largest message determines the size, so that rotating messages do not
+        // move the indicator around.
+        let text = self
+            .messages
+            .iter()
+            .fold(iced_widget::core::Size::ZERO, |size, message| {
+                let message = renderer.measure(
+                    message,
+                    self.size.unwrap_or_else(|| renderer.default_size()),
+                    Default::default(),
+                    renderer.default_font(),
+                    limits.max(),
+                    Default::default(),
+                );
+                iced_widget::core::Size::new(
+                    size.width.max(message.width),
+                    size.height.max(message.height),
+                )
+            });
+
+        let mut indicator = layout_indicator(&if self.is_horizontal() {
+            limits.shrink(iced_widget::core::Size::new(text.width + self.spacing, 0.0))
+        } else {
+            limits.shrink(iced_widget::core::Size::new(
+                0.0,
+                text.height + self.spacing,
+            ))
+        });
+        let indicator_size = indicator.size();
+
+        let size = if self.is_horizontal() {
+            iced_widget::core::Size::new(
+                indicator_size.width + self.spacing + text.width,
+                indicator_size.height.max(text.height),
+            )
+        } else {
+            iced_widget::core::Size::new(
+                indicator_size.width.max(text.width),
+                indicator_size.height + self.spacing + text.height,
+            )
+        };
+
+        // The text and the indicator get centered on the cross axis.
+        let (indicator_position, text_position) = match self.placement {
+            Placement::Below => (
+                [(size.width - indicator_size.width) / 2.0, 0.0],
+                [
+                    (size.width - text.width) / 2.0,
+                    indicator_size.height + self.spacing,
+                ],
+            ),
+            Placement::Above => (
+                [
+                    (size.width - indicator_size.width) / 2.0,
+                    text.height + self.spacing,
+                ],
+                [(size.width - text.width) / 2.0, 0.0],
+            ),
+            Placement::Left => (
+                [
+                    text.width + self.spacing,
+                    (size.height - indicator_size.height) / 2.0,
+                ],
+                [0.0, (size.height - text.height) / 2.0],
+            ),
+            Placement::Right => (
+                [0.0, (size.height - indicator_size.height) / 2.0],
+                [
+                    indicator_size.width + self.spacing,
+                    (size.height - text.height) / 2.0,
+                ],
+            ),
+        };
+        indicator.move_to(indicator_position.into());
+        let mut text = iced_widget::core::layout::Node::new(text);
+        text.move_to(text_position.into());
+
+        iced_widget::core::layout::Node::with_children(size, vec![indicator, text])
+    }
+
+    /// Draws the text within the provided `layout` after `time` has elapsed, given the
+    /// duration of one of the indicator's `revolution`s, using the provided `color` unless
+    /// the label specifies its own.
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        style: &iced_widget::core::renderer::Style,
+        layout: iced_widget::core::Layout<'_>,
+        color: iced_widget::core::Color,
+        time: std::time::Duration,
+        revolution: std::time::Duration,
+    ) where
+        Renderer: iced_widget::core::text::Renderer,
+    {
+        if self.messages.is_empty() {
+            return;
+        }
+        let color = self.color.unwrap_or(color);
+        // Shorter messages stick to the indicator.
+        let horizontal_alignment = match self.placement {
+            Placement::Below | Placement::Above => iced_widget::core::alignment::Horizontal::Center,
+            Placement::Left => iced_widget::core::alignment::Horizontal::Right,
+            Placement::Right => iced_widget::core::alignment::Horizontal::Left,
+        };
+
+        let (index, fade) = self.messages_at(time, revolution);
+        let next = (index + 1) % self.messages.len();
+        for (index, alpha) in [(index, 1.0 - fade), (next, fade)] {
+            if alpha <= 0.0 {
+                continue;
+            }
+            iced_widget::core::widget::text::draw(
+                renderer,
+                style,
+                layout,
+                &self.messages[index],
+                self.size,
+                Default::default(),
+                None,
+                iced_widget::core::widget::text::Appearance {
+                    color: Some(iced_widget::core::Color {
+                        a: color.a * alpha,
+                        ..color
+                    }),
+                },
+                horizontal_alignment,
+                iced_widget::core::alignment::Vertical::Center,
+                Default::default(),
+            );
+        }
+    }
+}
+
+impl From<&str> for Label {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Label {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
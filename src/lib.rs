@@ -3,6 +3,14 @@
 //! built-in theme, namely, [`iced_widget::core::Renderer`] and [`iced_style::Theme`],
 //! respectively.
 //!
+//! The widget is not tied to the built-in theme, though: any theme type implementing
+//! the [`StyleSheet`] trait can be used. In Iced `0.10`, canvas frames can only be built
+//! from [`iced_widget::renderer::Renderer`], which is why the [`iced_widget::canvas::Program`]
+//! implementation targets that renderer (for any theme), while the [`Widget`] and the
+//! [`iced_widget::core::Element`] conversions are generic over any renderer for which
+//! [`LoadingIndicator`] implements [`iced_widget::canvas::Program`] and which can draw
+//! text (for the widget's [`Label`]).
+//!
 //! Internally, the widget uses a canvas to draw the indicator and takes charge of the animation
 //! itself by listening to [`iced_widget::core::window::Event::RedrawRequested`] events and
 //! requesting redraws.
@@ -12,67 +20,219 @@
 #![doc = include_str!("../examples/widget.rs")]
 //! ```
 
-/// The loading indicator's coloring style.
-#[derive(Default, Clone, Copy, Debug)]
-pub enum Style {
-    /// Based on [`iced_style::theme::Palette`]'s `text` property.
+pub mod style;
+
+pub mod loading_button;
+pub mod loading_overlay;
+pub mod skeleton;
+
+mod centered;
+mod config;
+mod label;
+mod shape;
+mod trail;
+
+pub use config::Config;
+pub use label::{
+    Label, Placement, Rotation, DEFAULT_CROSS_FADE_MS, DEFAULT_LABEL_SPACING,
+    DEFAULT_ROTATION_INTERVAL_MS,
+};
+pub use loading_button::LoadingButton;
+pub use loading_overlay::LoadingOverlay;
+pub use shape::Shape;
+pub use skeleton::Skeleton;
+pub use style::{Appearance, Strength, Style, StyleSheet};
+pub use trail::{Falloff, Inactive, Trail, DEFAULT_INACTIVE_ALPHA, LIGHTER_INACTIVE_ALPHA};
+
+/// The way in which the loading indicator gets animated.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Animation {
+    /// The active circle moves by one whole step at each tick.
+    #[default]
+    Discrete,
+    /// The animation keeps a continuous phase and the circles' opacities get
+    /// interpolated between steps, at the display's frame rate.
+    Smooth,
+}
+
+/// The way in which the [`Widget`] gets animated while its window is unfocused.
+/// Whatever the setting, the widget stops requesting redraws while its window is
+/// minimized. In both cases, the animation catches up with the elapsed time once
+/// the widget gets redrawn.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unfocused {
+    /// The widget keeps being animated at full speed.
     #[default]
-    TextColor,
-    /// Based on [`iced_style::theme::Palette`]'s `primary` property.
-    PrimaryColor,
-    /// Used for a custom, user provided [`iced_widget::core::Color`].
-    CustomColor(iced_widget::core::Color),
+    Animate,
+    /// The widget gets redrawn at most once per provided duration (e.g. `500ms`
+    /// for two frames per second).
+    Throttle(std::time::Duration),
+    /// The widget stops requesting redraws until its window gets focused again.
+    Pause,
 }
 
-/// A simple tuple structure that is used to keep track
-/// of the loading indicator's active (i.e. completely opact) circle.
+/// The kind of loading indicator to be drawn.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A ring of circles, in which the active circle is followed by a fading [`Trail`].
+    #[default]
+    Dots,
+    /// A Material-style arc that rotates while growing and shrinking, drawn with a
+    /// stroke whose width is the [`Appearance`]'s `dot_radius_ratio` times the indicator's
+    /// radius. A full cycle takes "number of circles" times the tick duration, and the
+    /// arc looks best with [`Animation::Smooth`].
+    Arc,
+    /// A horizontal bar filling the available width, whose height is the indicator's
+    /// size, along which a segment slides back and forth (or which gets filled from the
+    /// left in the determinate progress mode). The bar's background uses the [`Appearance`]'s
+    /// track color, or the inactive color when there is none. Like [`Kind::Arc`], the
+    /// bar looks best with [`Animation::Smooth`].
+    Bar,
+    /// Three dots bouncing one after the other, as in a chat application's "typing"
+    /// indicator, meant to sit inline with text: the indicator's size is its height, and
+    /// its width is three times that. A bouncing dot goes from the inactive color to the
+    /// active one. In the determinate progress mode, the dots get filled from the left.
+    /// Like [`Kind::Arc`], the dots look best with [`Animation::Smooth`].
+    Typing,
+    /// Concentric rings expanding from the center while fading out, as in a "radar ping",
+    /// which suits "listening" or "waiting" states better than a rotating indicator. The
+    /// rings' stroke width is the [`Appearance`]'s `dot_radius_ratio` times half the
+    /// indicator's radius. In the determinate progress mode, a single ring grows from
+    /// the center. Like [`Kind::Arc`], the rings look best with [`Animation::Smooth`].
+    Ripple,
+}
+
+impl Kind {
+    /// Returns the width of a loading indicator of this kind with the provided `size`.
+    fn width(&self, size: f32) -> iced_widget::core::Length {
+        match self {
+            Self::Dots | Self::Arc | Self::Ripple => iced_widget::core::Length::Fixed(size),
+            Self::Bar => iced_widget::core::Length::Fill,
+            Self::Typing => iced_widget::core::Length::Fixed(size * TYPING_DOTS as f32),
+        }
+    }
+
+    /// Returns the height of a loading indicator of this kind with the provided `size`.
+    fn height(&self, size: f32) -> iced_widget::core::Length {
+        iced_widget::core::Length::Fixed(size)
+    }
+
+    /// Returns the largest size that a loading indicator of this kind can be drawn
+    /// at within the `available` size, keeping its aspect ratio.
+    fn fit(&self, available: iced_widget::core::Size) -> iced_widget::core::Size {
+        match self {
+            Self::Dots | Self::Arc | Self::Ripple => {
+                let side = available.width.min(available.height);
+                iced_widget::core::Size::new(side, side)
+            }
+            Self::Bar => available,
+            Self::Typing => {
+                let height = available.height.min(available.width / TYPING_DOTS as f32);
+                iced_widget::core::Size::new(height * TYPING_DOTS as f32, height)
+            }
+        }
+    }
+}
+
+/// A simple structure that is used to keep track
+/// of the loading indicator's active (i.e. completely opact) circle, along
+/// with the number of circles it wraps around.
 #[derive(Clone, Copy, Debug)]
-pub struct Index(usize);
+pub struct Index {
+    /// The active circle's index.
+    value: usize,
+    /// The number of circles after which the index wraps back to zero.
+    number_of_circles: usize,
+}
 
 impl Default for Index {
     fn default() -> Self {
-        Self(0)
+        Self::with_number_of_circles(DEFAULT_NUMBER_OF_CIRCLES)
     }
 }
 
 impl Index {
     /// A factory method that returns an [`Index`] with its
-    /// internal state set to zero.
+    /// internal state set to zero, wrapping around [`DEFAULT_NUMBER_OF_CIRCLES`].
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// A factory method that returns an [`Index`] with its internal state set
+    /// to zero, wrapping around the specified `number_of_circles`, which should
+    /// match the one used by the [`LoadingIndicator`] that will be drawn with it.
+    ///
+    /// **Panics** if `number_of_circles` is zero.
+    pub fn with_number_of_circles(number_of_circles: usize) -> Self {
+        assert!(
+            number_of_circles > 0,
+            "the number of circles must be positive"
+        );
+        Self {
+            value: 0,
+            number_of_circles,
+        }
+    }
+
     /// A method that mutates the internal index by increasing
-    /// it by one or by resetting it back to zero if "[`NUMBER_OF_CIRCLES`]
+    /// it by one or by resetting it back to zero if "the number of circles
     /// minus one" gets reached.
     pub fn tick(&mut self) {
-        if self.0 == (NUMBER_OF_CIRCLES - 1) {
-            self.0 = 0;
+        if self.value >= (self.number_of_circles - 1) {
+            self.value = 0;
         } else {
-            self.0 += 1;
+            self.value += 1;
         }
     }
+
+    /// Returns the active circle's index.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Returns the number of circles after which the index wraps back to zero.
+    pub fn number_of_circles(&self) -> usize {
+        self.number_of_circles
+    }
 }
 
-/// The number of circles from which the loading indicator
-/// is made up. The current value is `12` and cannot be modified.
+/// The default number of circles from which the loading indicator
+/// is made up. The current value is `12`, which can be overridden using
+/// [`LoadingIndicator::number_of_circles`] or [`Widget::number_of_circles`].
 // PRIVATE NOTES
 // - Could have been: 8, (10, 12), 15, 18, 20, 24...
 // - I think that 12 points is the best overall, but 10 points would have
 // been Ok as well.
-pub const NUMBER_OF_CIRCLES: usize = 12;
+pub const DEFAULT_NUMBER_OF_CIRCLES: usize = 12;
+
+/// The default size (in pixels) of the loading indicator, used by
+/// [`Widget::new`] and [`Config::new`].
+pub const DEFAULT_SIZE: f32 = 40.0;
+
+/// The default "tick interval" (i.e. animation speed), in milliseconds.
+pub const DEFAULT_TICK_DURATION_MS: u64 = 80;
+
+/// The number of dots of a [`Kind::Typing`] indicator.
+const TYPING_DOTS: usize = 3;
 
-/// A private helper function used to get the index offset
-/// of a point `distance` steps prior the current `index`.
-fn index_offset(index: usize, distance: usize) -> usize {
-    if distance >= NUMBER_OF_CIRCLES || index >= NUMBER_OF_CIRCLES {
+/// The number of rings of a [`Kind::Ripple`] indicator.
+const RIPPLE_RINGS: usize = 3;
+
+/// The difference below which an eased progress value snaps to its target.
+const PROGRESS_EPSILON: f32 = 0.001;
+
+/// The number of revolutions for which the clock of a widget that was given an id
+/// gets kept once the widget stops receiving events (see [`Widget::id`]).
+const CLOCK_RETENTION_REVOLUTIONS: u32 = 4;
+
+/// A private helper function used to get the number of steps
+/// by which circle `i` trails the current `index`, for a
+/// loading indicator made up of `number_of_circles` circles.
+fn index_distance(index: usize, i: usize, number_of_circles: usize) -> usize {
+    if index >= number_of_circles || i >= number_of_circles {
         panic!("invalid usage");
     }
-    if index >= distance {
-        index - distance
-    } else {
-        (NUMBER_OF_CIRCLES + index) - distance
-    }
+    (number_of_circles + index - i) % number_of_circles
 }
 
 /// The loading indicator structure, which implements the [`iced_widget::canvas::Program`]
@@ -83,41 +243,105 @@ fn index_offset(index: usize, distance: usize) -> usize {
 /// (see `examples/in_app.rs`), but in most cases the
 /// user will prefer to rely on the [`Widget`] wrapper type, which latter will itself take care of the
 /// animation (see `examples/widget.rs`).
-pub struct LoadingIndicator {
+pub struct LoadingIndicator<Theme = iced_style::Theme>
+where
+    Theme: StyleSheet,
+{
     /// The loading indicator's size (in pixels).
     size: f32,
     /// The loading indicator's active index.
     index: Index,
     /// The loading indicator's style.
-    style: Style,
-    /// Whether to use a smaller alpha channel (`0.025` vs `0.1`) for the
-    /// inactive index background color.
-    lighter_inactive: bool,
+    style: Theme::Style,
+    /// The number of circles from which the loading indicator is made up.
+    number_of_circles: usize,
+    /// The configuration of the trail following the active circle.
+    trail: Trail,
+    /// The kind of loading indicator to be drawn.
+    kind: Kind,
+    /// The shape of the circles of a [`Kind::Dots`] loading indicator.
+    shape: Shape,
+    /// The loading indicator's continuous phase (in the `0.0..1.0` range), which,
+    /// when set, takes precedence over the `index`.
+    phase: Option<f32>,
+    /// The loading indicator's determinate progress (in the `0.0..=1.0` range), which,
+    /// when set, takes precedence over both the `index` and the `phase`.
+    progress: Option<f32>,
+    /// The color overriding the [`Appearance`]'s active color, if any, which is used
+    /// when the indicator inherits its parent's text color (see [`Widget::inherit_text_color`]).
+    text_color: Option<iced_widget::core::Color>,
 }
 
-impl LoadingIndicator {
+impl<Theme> LoadingIndicator<Theme>
+where
+    Theme: StyleSheet,
+{
     /// A factory method that can be used to instantiate the loading
     /// indicator with the specified `size` and `index`, with the
-    /// default coloring [`Style`] and `lighter_inactive` set to `false`.
+    /// default coloring [`Style`] and the default [`Trail`].
+    /// The number of circles is taken from the `index` (see [`Index::with_number_of_circles`]).
     pub fn new(size: f32, index: Index) -> Self {
         Self {
             size,
             index,
             style: Default::default(),
-            lighter_inactive: false,
+            number_of_circles: index.number_of_circles(),
+            trail: Default::default(),
+            kind: Default::default(),
+            shape: Default::default(),
+            phase: None,
+            progress: None,
+            text_color: None,
         }
     }
 
     /// A factory method that can be used to instantiate the loading
     /// indicator with the specified `size`, starting at index 0, with
-    /// `lighter_inactive` set to `false`, and using the default coloring [`Style`].
+    /// the default [`Trail`], and using the default coloring [`Style`].
     pub fn with_size(size: f32) -> Self {
-        Self::new(size, Index(0))
+        Self::new(size, Index::new())
+    }
+
+    /// A setter method that can be used to specify the number of circles from
+    /// which the loading indicator is made up (defaults to [`DEFAULT_NUMBER_OF_CIRCLES`]).
+    /// Smaller indicators usually look better with fewer circles, while larger ones
+    /// look better with more. The length of the trail following the active circle
+    /// adapts to that number.
+    ///
+    /// **Panics** if `value` is zero.
+    pub fn number_of_circles(self, value: usize) -> Self {
+        assert!(value > 0, "the number of circles must be positive");
+        Self {
+            number_of_circles: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the style (e.g. [`Style`] for
+    /// the built-in theme), which gets resolved into an [`Appearance`] by the theme.
+    pub fn style(self, style: impl Into<Theme::Style>) -> Self {
+        Self {
+            style: style.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the [`Trail`] following
+    /// the active circle.
+    pub fn trail(self, trail: Trail) -> Self {
+        Self { trail, ..self }
     }
 
-    /// A setter method that can be used to specify the coloring [`Style`].
-    pub fn style(self, style: Style) -> Self {
-        Self { style, ..self }
+    /// A setter method that can be used to specify the [`Kind`] of loading
+    /// indicator to be drawn (defaults to [`Kind::Dots`]).
+    pub fn kind(self, kind: Kind) -> Self {
+        Self { kind, ..self }
+    }
+
+    /// A setter method that can be used to specify the [`Shape`] of the circles
+    /// of a [`Kind::Dots`] loading indicator (defaults to [`Shape::Circle`]).
+    pub fn shape(self, shape: Shape) -> Self {
+        Self { shape, ..self }
     }
 
     /// A setter method that can be used to specify whether a lighter
@@ -126,112 +350,865 @@ impl LoadingIndicator {
     /// used; for `true`, the value `0.025` is used. The latter is useful on darker
     /// backgrounds (i.e. in a darker themed app), while the former will come out
     /// nice on a lighter background (i.e. in a lighter themed app).
+    ///
+    /// This is a shorthand for [`Trail::lighter_inactive`] applied to the current trail.
     pub fn lighter_inactive(self, value: bool) -> Self {
         Self {
-            lighter_inactive: value,
+            trail: self.trail.lighter_inactive(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify how the alpha channel of the
+    /// inactive circles gets determined, e.g. [`Inactive::Auto`] to have it follow the
+    /// theme's background. This is a shorthand for [`Trail::inactive`] applied to the current trail.
+    pub fn inactive(self, value: Inactive) -> Self {
+        Self {
+            trail: self.trail.inactive(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify a continuous phase, where
+    /// `0.0` corresponds to the first circle being active and `1.0` to a full revolution.
+    /// When set, the phase takes precedence over the [`Index`], and the circles' opacities
+    /// get interpolated between two consecutive indexes (see [`Animation::Smooth`]).
+    /// Values outside of the `0.0..1.0` range wrap around.
+    pub fn phase(self, value: f32) -> Self {
+        Self {
+            phase: Some(value),
+            ..self
+        }
+    }
+
+    /// A setter method that turns the loading indicator into a determinate
+    /// progress indicator, where `value` (clamped to the `0.0..=1.0` range) is the
+    /// fraction of circles that get filled clockwise, starting from the top. The last,
+    /// partially filled circle gets a proportionally interpolated color. When set,
+    /// the progress takes precedence over both the [`Index`] and the phase.
+    pub fn progress(self, value: f32) -> Self {
+        Self {
+            progress: Some(value.clamp(0.0, 1.0)),
+            ..self
+        }
+    }
+
+    /// A crate-private setter method used to override the [`Appearance`]'s active color.
+    pub(crate) fn text_color(self, value: iced_widget::core::Color) -> Self {
+        Self {
+            text_color: Some(value),
             ..self
         }
     }
 }
 
-type Renderer = iced_widget::renderer::Renderer<iced_style::Theme>;
+impl<Theme> LoadingIndicator<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// A private helper method that returns the color of the inactive
+    /// circles, for the provided `appearance`.
+    fn inactive_color(&self, appearance: &Appearance) -> iced_widget::core::Color {
+        appearance
+            .inactive_color
+            .unwrap_or(iced_widget::core::Color {
+                a: self
+                    .trail
+                    .inactive_alpha_for(appearance.color, appearance.background_color),
+                ..appearance.color
+            })
+    }
+
+    /// A private helper method that returns the color of the circle that is
+    /// `distance` steps behind the active one, for the provided `appearance`.
+    fn color(&self, appearance: &Appearance, distance: usize) -> iced_widget::core::Color {
+        let number_of_circles = self.number_of_circles;
+        let inactive_alpha = self
+            .trail
+            .inactive_alpha_for(appearance.color, appearance.background_color);
+        if distance == 0 {
+            appearance.color
+        } else if distance > self.trail.resolved_length(number_of_circles) {
+            self.inactive_color(appearance)
+        } else {
+            let color = appearance.trail_color.unwrap_or(appearance.color);
+            iced_widget::core::Color {
+                a: color.a
+                    * self
+                        .trail
+                        .alpha(distance, number_of_circles, inactive_alpha),
+                ..color
+            }
+        }
+    }
 
-impl<M> iced_widget::canvas::Program<M, Renderer> for LoadingIndicator {
-    type State = ();
+    /// A private helper method that returns the color of each circle,
+    /// based on the active index (or phase), the trail configuration
+    /// and the provided `appearance`.
+    fn colors(&self, appearance: &Appearance) -> Vec<iced_widget::core::Color> {
+        let number_of_circles = self.number_of_circles;
+        if let Some(progress) = self.progress {
+            return self.progress_colors(appearance, progress);
+        }
+        let (index, fraction) = match self.phase {
+            Some(phase) => {
+                let position = phase.rem_euclid(1.0) * (number_of_circles as f32);
+                (
+                    (position.floor() as usize) % number_of_circles,
+                    position.fract(),
+                )
+            }
+            None => (self.index.value() % number_of_circles, 0.0),
+        };
+        let next_index = (index + 1) % number_of_circles;
+        (0..number_of_circles)
+            .map(|i| {
+                let color = self.color(appearance, index_distance(index, i, number_of_circles));
+                if fraction == 0.0 {
+                    return color;
+                }
+                let next_color =
+                    self.color(appearance, index_distance(next_index, i, number_of_circles));
+                mix_colors(color, next_color, fraction)
+            })
+            .collect()
+    }
 
-    // See [clock example](https://github.com/iced-rs/iced/blob/master/examples/clock/src/main.rs)
-    // for a nice example on how to use the Canvas with the Program trait.
+    /// A private helper method that returns how far (between `0.0` and `1.0`) the
+    /// indicator is into its current cycle, based on the active phase (or index).
+    fn cycle_phase(&self) -> f32 {
+        self.phase
+            .unwrap_or((self.index.value() as f32) / (self.index.number_of_circles() as f32))
+            .rem_euclid(1.0)
+    }
 
-    fn draw(
+    /// A private helper method that returns the angles (in radians, clockwise from
+    /// the positive x axis) between which the arc of a [`Kind::Arc`] indicator gets
+    /// drawn, or `None` when there is nothing to draw.
+    fn arc_angles(&self) -> Option<(f32, f32)> {
+        const TOP: f32 = -std::f32::consts::FRAC_PI_2;
+        const MIN_SWEEP: f32 = std::f32::consts::PI / 12.0;
+        // The arc grows by three quarters of a turn during the first half of a cycle,
+        // and shrinks back during the second half, while the whole arc rotates by a
+        // quarter of a turn, so that a cycle ends where it started.
+        const GROWTH: f32 = 3.0 * std::f32::consts::FRAC_PI_2;
+        const ROTATION: f32 = std::f32::consts::FRAC_PI_2;
+
+        if let Some(progress) = self.progress {
+            return (progress > 0.0).then_some((TOP, TOP + progress * 2.0 * std::f32::consts::PI));
+        }
+
+        let phase = self.cycle_phase();
+        let (head, tail) = if phase < 0.5 {
+            (GROWTH * smoothstep(phase * 2.0), 0.0)
+        } else {
+            (GROWTH, GROWTH * smoothstep(phase * 2.0 - 1.0))
+        };
+        let start = TOP + ROTATION * phase + tail;
+        Some((start, TOP + ROTATION * phase + head + MIN_SWEEP))
+    }
+
+    /// A private helper method that returns the horizontal span (as fractions of
+    /// the bar's width) of the segment of a [`Kind::Bar`] indicator, or `None` when
+    /// there is nothing to draw.
+    fn bar_segment(&self) -> Option<(f32, f32)> {
+        const SEGMENT_WIDTH: f32 = 0.4;
+
+        if let Some(progress) = self.progress {
+            return (progress > 0.0).then_some((0.0, progress));
+        }
+
+        let phase = self.cycle_phase();
+        // The segment enters from the left and leaves on the right.
+        let start = -SEGMENT_WIDTH + (1.0 + SEGMENT_WIDTH) * smoothstep(phase);
+        let (start, end) = (start.max(0.0), (start + SEGMENT_WIDTH).min(1.0));
+        (end > start).then_some((start, end))
+    }
+
+    /// A private helper method that returns the height (as a fraction of the maximum
+    /// bounce) and color of each dot of a [`Kind::Typing`] indicator.
+    fn typing_dots(&self, appearance: &Appearance) -> Vec<(f32, iced_widget::core::Color)> {
+        // The fraction of a cycle during which a dot bounces, and the delay between
+        // two consecutive dots starting to bounce (also as a fraction of a cycle).
+        const BOUNCE: f32 = 0.4;
+        const DELAY: f32 = 0.15;
+
+        let inactive_color = self.inactive_color(appearance);
+
+        if let Some(progress) = self.progress {
+            let filled = progress * (TYPING_DOTS as f32);
+            return (0..TYPING_DOTS)
+                .map(|i| {
+                    let fraction = (filled - i as f32).clamp(0.0, 1.0);
+                    (0.0, mix_colors(inactive_color, appearance.color, fraction))
+                })
+                .collect();
+        }
+
+        let phase = self.cycle_phase();
+        (0..TYPING_DOTS)
+            .map(|i| {
+                let t = (phase - DELAY * i as f32).rem_euclid(1.0);
+                let height = if t < BOUNCE {
+                    (std::f32::consts::PI * t / BOUNCE).sin()
+                } else {
+                    0.0
+                };
+                (height, mix_colors(inactive_color, appearance.color, height))
+            })
+            .collect()
+    }
+
+    /// A private helper method that returns the radius (as a fraction of the
+    /// indicator's radius) and color of each ring of a [`Kind::Ripple`] indicator.
+    fn ripple_rings(&self, appearance: &Appearance) -> Vec<(f32, iced_widget::core::Color)> {
+        if let Some(progress) = self.progress {
+            return vec![(progress, appearance.color)];
+        }
+
+        let phase = self.cycle_phase();
+        (0..RIPPLE_RINGS)
+            .map(|i| {
+                let t = (phase + (i as f32) / (RIPPLE_RINGS as f32)).rem_euclid(1.0);
+                // The rings decelerate as they expand.
+                let radius = 1.0 - (1.0 - t) * (1.0 - t);
+                let color = iced_widget::core::Color {
+                    a: appearance.color.a * (1.0 - t),
+                    ..appearance.color
+                };
+                (radius, color)
+            })
+            .collect()
+    }
+
+    /// A private helper method that returns the size of the frame onto which
+    /// the loading indicator gets drawn, given the `bounds` it was laid out in, which
+    /// may be smaller than the indicator's size when the layout limits constrain it.
+    fn frame_size(&self, bounds: iced_widget::core::Rectangle) -> iced_widget::core::Size {
+        self.kind.fit(iced_widget::core::Size::new(
+            bounds.width,
+            self.size.min(bounds.height),
+        ))
+    }
+
+    /// A private helper method that returns what needs to be drawn
+    /// for the provided `appearance`.
+    fn drawing(&self, appearance: &Appearance) -> Drawing {
+        match self.kind {
+            Kind::Dots => Drawing::Circles(self.shape.clone(), self.colors(appearance)),
+            Kind::Arc => Drawing::Arc(self.arc_angles()),
+            Kind::Bar => Drawing::Bar(
+                appearance
+                    .track_color
+                    .unwrap_or(self.inactive_color(appearance)),
+                self.bar_segment(),
+            ),
+            Kind::Typing => Drawing::Typing(self.typing_dots(appearance)),
+            Kind::Ripple => Drawing::Ripple(self.ripple_rings(appearance)),
+        }
+    }
+
+    /// A private helper method that returns the color of each circle when
+    /// the loading indicator shows the provided determinate `progress`.
+    fn progress_colors(
         &self,
-        _state: &Self::State,
-        renderer: &Renderer,
-        theme: &iced_style::Theme,
-        _bounds: iced_widget::core::Rectangle,
-        _cursor: iced_widget::core::mouse::Cursor,
-    ) -> Vec<<Renderer as iced_widget::canvas::Renderer>::Geometry> {
-        let size = iced_widget::core::Size::new(self.size, self.size);
+        appearance: &Appearance,
+        progress: f32,
+    ) -> Vec<iced_widget::core::Color> {
+        let number_of_circles = self.number_of_circles;
+        // The circles get drawn clockwise starting from the bottom, hence the
+        // offset of the first circle at (or right after) the top.
+        let top = number_of_circles.div_ceil(2);
+        let filled = progress * (number_of_circles as f32);
+        let inactive_color = self.inactive_color(appearance);
+        (0..number_of_circles)
+            .map(|i| {
+                let position = ((number_of_circles + i - top) % number_of_circles) as f32;
+                let fraction = (filled - position).clamp(0.0, 1.0);
+                mix_colors(inactive_color, appearance.color, fraction)
+            })
+            .collect()
+    }
+}
+
+/// A private helper function that linearly interpolates between colors `a` and `b`.
+fn mix_colors(
+    a: iced_widget::core::Color,
+    b: iced_widget::core::Color,
+    fraction: f32,
+) -> iced_widget::core::Color {
+    let mix = |a: f32, b: f32| a + (b - a) * fraction;
+    iced_widget::core::Color {
+        r: mix(a.r, b.r),
+        g: mix(a.g, b.g),
+        b: mix(a.b, b.b),
+        a: mix(a.a, b.a),
+    }
+}
+
+/// A private helper function that eases `t` (between `0.0` and `1.0`) in and out.
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
 
-        let mut frame = iced_widget::canvas::Frame::new(renderer, size.clone());
+/// What a [`LoadingIndicator`] draws, which gets compared with what it drew last,
+/// to determine which parts of the cached geometry need to be drawn again.
+#[derive(Debug, Clone, PartialEq)]
+enum Drawing {
+    /// The shape and the color of each circle of a [`Kind::Dots`] indicator.
+    Circles(Shape, Vec<iced_widget::core::Color>),
+    /// The start and end angles of a [`Kind::Arc`] indicator's arc, if any.
+    Arc(Option<(f32, f32)>),
+    /// The background color of a [`Kind::Bar`] indicator, along with the
+    /// horizontal span of its segment, if any.
+    Bar(iced_widget::core::Color, Option<(f32, f32)>),
+    /// The height (as a fraction of the maximum bounce) and color
+    /// of each dot of a [`Kind::Typing`] indicator.
+    Typing(Vec<(f32, iced_widget::core::Color)>),
+    /// The radius (as a fraction of the indicator's radius) and color
+    /// of each ring of a [`Kind::Ripple`] indicator.
+    Ripple(Vec<(f32, iced_widget::core::Color)>),
+}
 
-        let center = frame.center();
-        let radius = frame.width().min(frame.height()) / 2.0;
+/// A private helper function that draws the expanding `rings` onto the `frame`,
+/// along with the `appearance`'s track, if any, around the largest ring.
+fn draw_ripple(
+    frame: &mut iced_widget::canvas::Frame,
+    appearance: &Appearance,
+    rings: &[(f32, iced_widget::core::Color)],
+) {
+    let center = frame.center();
+    let radius = frame.width().min(frame.height()) / 2.0;
 
-        let point_size: f32 = radius * 0.15;
+    let width = radius * appearance.dot_radius_ratio / 2.0;
+    let orbit = radius - width / 2.0;
 
-        let point = iced_widget::canvas::Path::circle(
-            iced_widget::core::Point::new(0.0, radius - point_size * 1.1), // NOTE: 1.1 because I noticed clipping in practice. This needs more attention...
-            point_size,
+    if let Some(track_color) = appearance.track_color {
+        frame.stroke(
+            &iced_widget::canvas::Path::circle(center, orbit),
+            iced_widget::canvas::Stroke::default()
+                .with_color(track_color)
+                .with_width(width),
         );
+    }
 
-        frame.translate(iced_widget::core::Vector::new(center.x, center.y));
+    for (fraction, color) in rings {
+        if *fraction <= 0.0 {
+            continue;
+        }
+        frame.stroke(
+            &iced_widget::canvas::Path::circle(center, orbit * fraction),
+            iced_widget::canvas::Stroke::default()
+                .with_color(*color)
+                .with_width(width),
+        );
+    }
+}
 
-        let color = match self.style {
-            Style::TextColor => theme.palette().text,
-            Style::PrimaryColor => theme.palette().primary,
-            // Style::PrimaryColor => theme.extended_palette().primary.strong.color, // This one would match the default Button color
-            Style::CustomColor(color) => color,
-        };
+/// A private helper function that draws the bouncing `dots` onto the `frame`.
+fn draw_typing(frame: &mut iced_widget::canvas::Frame, dots: &[(f32, iced_widget::core::Color)]) {
+    let cell = frame.width() / (dots.len() as f32);
+    let radius = frame.height() / 4.0;
+    // A dot rests at the bottom of the frame and bounces up to its top.
+    let rest = frame.height() - radius;
+    let bounce = frame.height() - 2.0 * radius;
 
-        let index = self.index.0;
-        for i in 0..NUMBER_OF_CIRCLES {
-            let color = if i == index {
-                color
-            } else if index_offset(index, 1) == i {
-                iced_widget::core::Color { a: 0.8, ..color }
-            } else if index_offset(index, 2) == i {
-                iced_widget::core::Color { a: 0.6, ..color }
-            } else if index_offset(index, 3) == i {
-                iced_widget::core::Color { a: 0.4, ..color }
-            } else if index_offset(index, 4) == i {
-                iced_widget::core::Color { a: 0.20, ..color }
-            } else {
-                let a = if self.lighter_inactive { 0.025 } else { 0.1 };
-                iced_widget::core::Color { a, ..color }
-            };
+    for (i, (height, color)) in dots.iter().enumerate() {
+        let center = iced_widget::core::Point::new(cell * (i as f32 + 0.5), rest - bounce * height);
+        frame.fill(&iced_widget::canvas::Path::circle(center, radius), *color);
+    }
+}
+
+/// A private helper function that draws the bar's background, using the
+/// provided `track_color`, and its `segment` (if any) onto the `frame`.
+fn draw_bar(
+    frame: &mut iced_widget::canvas::Frame,
+    appearance: &Appearance,
+    track_color: iced_widget::core::Color,
+    segment: Option<(f32, f32)>,
+) {
+    let (width, height) = (frame.width(), frame.height());
+
+    frame.fill_rectangle(
+        iced_widget::core::Point::ORIGIN,
+        iced_widget::core::Size::new(width, height),
+        track_color,
+    );
+
+    if let Some((start, end)) = segment {
+        frame.fill_rectangle(
+            iced_widget::core::Point::new(start * width, 0.0),
+            iced_widget::core::Size::new((end - start) * width, height),
+            appearance.color,
+        );
+    }
+}
+
+/// A private helper function that draws the arc spanning the provided `angles`
+/// (if any) onto the `frame`, along with the `appearance`'s track, if any.
+fn draw_arc(
+    frame: &mut iced_widget::canvas::Frame,
+    appearance: &Appearance,
+    angles: Option<(f32, f32)>,
+) {
+    let center = frame.center();
+    let radius = frame.width().min(frame.height()) / 2.0;
+
+    let width = radius * appearance.dot_radius_ratio;
+    let orbit = radius - width / 2.0;
+
+    if let Some(track_color) = appearance.track_color {
+        frame.stroke(
+            &iced_widget::canvas::Path::circle(center, orbit),
+            iced_widget::canvas::Stroke::default()
+                .with_color(track_color)
+                .with_width(width),
+        );
+    }
+
+    if let Some((start_angle, end_angle)) = angles {
+        let arc = iced_widget::canvas::Path::new(|builder| {
+            builder.arc(iced_widget::canvas::path::Arc {
+                center,
+                radius: orbit,
+                start_angle,
+                end_angle,
+            })
+        });
+        frame.stroke(
+            &arc,
+            iced_widget::canvas::Stroke::default()
+                .with_color(appearance.color)
+                .with_width(width)
+                .with_line_cap(iced_widget::canvas::LineCap::Round),
+        );
+    }
+}
+
+/// A private helper function that returns the radius of the circles drawn onto the
+/// `frame` with the provided `appearance`, along with the radius of the orbit along
+/// which they get placed.
+fn circle_metrics(frame: &iced_widget::canvas::Frame, appearance: &Appearance) -> (f32, f32) {
+    let radius = frame.width().min(frame.height()) / 2.0;
+    let point_size: f32 = radius * appearance.dot_radius_ratio;
+    let orbit = radius - point_size * 1.1; // NOTE: 1.1 because I noticed clipping in practice. This needs more attention...
+    (point_size, orbit)
+}
+
+/// A private helper function that draws the `appearance`'s track, if any, onto
+/// the `frame`, along which the circles of a [`Kind::Dots`] indicator get placed.
+fn draw_track(frame: &mut iced_widget::canvas::Frame, appearance: &Appearance) {
+    let Some(track_color) = appearance.track_color else {
+        return;
+    };
+    let (point_size, orbit) = circle_metrics(frame, appearance);
+    frame.stroke(
+        &iced_widget::canvas::Path::circle(frame.center(), orbit),
+        iced_widget::canvas::Stroke::default()
+            .with_color(track_color)
+            .with_width(point_size * 2.0),
+    );
+}
+
+/// A private helper function that draws circle `i` out of `number_of_circles`,
+/// with the provided `shape` and `color`, onto the `frame`.
+fn draw_circle(
+    frame: &mut iced_widget::canvas::Frame,
+    appearance: &Appearance,
+    shape: &Shape,
+    i: usize,
+    number_of_circles: usize,
+    color: iced_widget::core::Color,
+) {
+    let center = frame.center();
+    let radius = frame.width().min(frame.height()) / 2.0;
+    let (point_size, orbit) = circle_metrics(frame, appearance);
+
+    let angle_in_degrees = 360.0 / (number_of_circles as f32);
+    let angle_in_radians = angle_in_degrees * (i as f32) / 180.0 * std::f32::consts::PI;
+
+    frame.translate(iced_widget::core::Vector::new(center.x, center.y));
+    frame.rotate(angle_in_radians);
+    shape.draw(frame, radius, point_size, orbit, color);
+}
+
+/// A private helper function that draws the part of the `drawing` that does not
+/// change from one frame to the next onto the `frame`, i.e. the whole indicator,
+/// except for a [`Kind::Dots`] indicator, whose circles get drawn separately.
+fn draw_base(frame: &mut iced_widget::canvas::Frame, appearance: &Appearance, drawing: &Drawing) {
+    match drawing {
+        Drawing::Circles(..) => draw_track(frame, appearance),
+        Drawing::Arc(angles) => draw_arc(frame, appearance, *angles),
+        Drawing::Bar(track_color, segment) => draw_bar(frame, appearance, *track_color, *segment),
+        Drawing::Typing(dots) => draw_typing(frame, dots),
+        Drawing::Ripple(rings) => draw_ripple(frame, appearance, rings),
+    }
+}
+
+/// The inputs from which a [`LoadingIndicator`] gets drawn, which, when unchanged
+/// from one frame to the next, let the cached geometry be reused without working
+/// out what needs to be drawn again.
+#[derive(Debug, Clone, PartialEq)]
+struct Inputs {
+    /// The size of the frame onto which the indicator gets drawn.
+    size: iced_widget::core::Size,
+    /// See [`Appearance`].
+    appearance: Appearance,
+    /// See [`LoadingIndicator::kind`].
+    kind: Kind,
+    /// See [`LoadingIndicator::shape`].
+    shape: Shape,
+    /// See [`LoadingIndicator::number_of_circles`].
+    number_of_circles: usize,
+    /// See [`LoadingIndicator::trail`].
+    trail: Trail,
+    /// The value of [`LoadingIndicator::index`].
+    index: usize,
+    /// See [`LoadingIndicator::phase`].
+    phase: Option<f32>,
+    /// See [`LoadingIndicator::progress`].
+    progress: Option<f32>,
+}
+
+impl Inputs {
+    /// Returns whether the indicator drawn from `self` has the same track and circle
+    /// placement as the one drawn from `other`, i.e. whether only its colors may differ.
+    fn same_layout(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.appearance == other.appearance
+            && self.kind == other.kind
+            && self.shape == other.shape
+            && self.number_of_circles == other.number_of_circles
+    }
+}
+
+/// The [`LoadingIndicator`]'s drawing state, which keeps the tessellated geometry
+/// around, so that redrawing an unchanged indicator does not require tessellating
+/// anything again, and that moving a [`Kind::Dots`] indicator to its next index only
+/// requires tessellating the circles whose color changed (i.e. the active circle and
+/// its trail), whatever the number of circles.
+#[derive(Debug, Default)]
+pub struct DrawCache {
+    /// The cached geometry of the whole indicator, or, for a [`Kind::Dots`]
+    /// indicator, of its track.
+    geometry: iced_widget::canvas::Cache,
+    /// The cached geometry of each circle of a [`Kind::Dots`] indicator.
+    circles: std::cell::RefCell<Vec<iced_widget::canvas::Cache>>,
+    /// The inputs from which the cached geometry was drawn, along with what they
+    /// resolved to.
+    drawn: std::cell::RefCell<Option<(Inputs, Drawing)>>,
+}
+
+impl<M, Theme> iced_widget::canvas::Program<M, iced_widget::renderer::Renderer<Theme>>
+    for LoadingIndicator<Theme>
+where
+    Theme: StyleSheet,
+{
+    type State = DrawCache;
+
+    // See [clock example](https://github.com/iced-rs/iced/blob/master/examples/clock/src/main.rs)
+    // for a nice example on how to use the Canvas with the Program trait.
 
-            let angle_in_degrees = 360.0 / (NUMBER_OF_CIRCLES as f32);
-            let angle_in_radians = angle_in_degrees * (i as f32) / 180.0 * std::f32::consts::PI;
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &iced_widget::renderer::Renderer<Theme>,
+        theme: &Theme,
+        bounds: iced_widget::core::Rectangle,
+        _cursor: iced_widget::core::mouse::Cursor,
+    ) -> Vec<iced_widget::canvas::Geometry> {
+        let size = self.frame_size(bounds);
+
+        let mut appearance = theme.appearance(&self.style);
+        if let Some(text_color) = self.text_color {
+            appearance.color = text_color;
+        }
+        let inputs = Inputs {
+            size,
+            appearance,
+            kind: self.kind,
+            shape: self.shape.clone(),
+            number_of_circles: self.number_of_circles,
+            trail: self.trail.clone(),
+            index: self.index.value(),
+            phase: self.phase,
+            progress: self.progress,
+        };
 
-            frame.with_save(|f| {
-                f.rotate(angle_in_radians);
-                f.fill(&point, color);
-            });
+        let mut drawn = state.drawn.borrow_mut();
+        let mut circles = state.circles.borrow_mut();
+        if drawn.as_ref().map(|(previous, _)| previous) != Some(&inputs) {
+            let drawing = self.drawing(&appearance);
+            let same_layout = drawn
+                .as_ref()
+                .is_some_and(|(previous, _)| previous.same_layout(&inputs));
+            if !same_layout {
+                state.geometry.clear();
+                circles.clear();
+            }
+            match (drawn.as_ref().map(|(_, previous)| previous), &drawing) {
+                (Some(Drawing::Circles(_, previous)), Drawing::Circles(_, colors))
+                    if same_layout =>
+                {
+                    for ((circle, previous), color) in circles.iter().zip(previous).zip(colors) {
+                        if previous != color {
+                            circle.clear();
+                        }
+                    }
+                }
+                (_, Drawing::Circles(_, colors)) => {
+                    circles.resize_with(colors.len(), Default::default)
+                }
+                _ => state.geometry.clear(),
+            }
+            *drawn = Some((inputs, drawing));
         }
 
-        vec![frame.into_geometry()]
+        let Some((_, drawing)) = drawn.as_ref() else {
+            return Vec::new();
+        };
+        let base = state.geometry.draw(renderer, size, |frame| {
+            draw_base(frame, &appearance, drawing)
+        });
+        let Drawing::Circles(shape, colors) = drawing else {
+            return vec![base];
+        };
+        std::iter::once(base)
+            .chain(
+                circles
+                    .iter()
+                    .zip(colors)
+                    .enumerate()
+                    .map(|(i, (circle, color))| {
+                        circle.draw(renderer, size, |frame| {
+                            draw_circle(frame, &appearance, shape, i, colors.len(), *color)
+                        })
+                    }),
+            )
+            .collect()
     }
 }
 
-impl<'a, M> std::convert::From<LoadingIndicator> for iced_widget::core::Element<'a, M, Renderer>
+impl<'a, M, Theme, Renderer> std::convert::From<LoadingIndicator<Theme>>
+    for iced_widget::core::Element<'a, M, Renderer>
 where
     M: 'a + Clone,
+    Theme: 'a + StyleSheet,
+    Renderer: 'a + iced_widget::canvas::Renderer,
+    LoadingIndicator<Theme>: iced_widget::canvas::Program<M, Renderer>,
 {
-    fn from(value: LoadingIndicator) -> Self {
-        let s = value.size;
-        Self::new(
-            iced_widget::canvas(value)
-                .width(iced_widget::core::Length::Fixed(s))
-                .height(iced_widget::core::Length::Fixed(s)),
-        )
+    fn from(value: LoadingIndicator<Theme>) -> Self {
+        let (width, height) = (value.kind.width(value.size), value.kind.height(value.size));
+        Self::new(iced_widget::canvas(value).width(width).height(height))
+    }
+}
+
+/// Whether the widget is animated, frozen or stopped (see [`Widget::paused`]
+/// and [`Widget::running`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    /// The widget is animated.
+    Running,
+    /// The widget is frozen on its current frame.
+    Paused,
+    /// The widget is not drawn, and starts over once running again.
+    Stopped,
+}
+
+/// A private helper function that returns the epoch from which synchronized
+/// widgets derive their animation (see [`Widget::synchronized`]), i.e. the first
+/// moment at which it was needed.
+fn epoch() -> std::time::Instant {
+    static EPOCH: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    *EPOCH.get_or_init(std::time::Instant::now)
+}
+
+/// A private helper function that delays the provided redraw `request` so that
+/// it does not happen before the `throttle` interval, if any, has elapsed from `now`.
+fn throttled(
+    request: iced_widget::core::window::RedrawRequest,
+    now: std::time::Instant,
+    throttle: Option<std::time::Duration>,
+) -> iced_widget::core::window::RedrawRequest {
+    let Some(throttle) = throttle else {
+        return request;
+    };
+    let earliest = now + throttle;
+    match request {
+        iced_widget::core::window::RedrawRequest::NextFrame => {
+            iced_widget::core::window::RedrawRequest::At(earliest)
+        }
+        iced_widget::core::window::RedrawRequest::At(at) => {
+            iced_widget::core::window::RedrawRequest::At(at.max(earliest))
+        }
     }
 }
 
+/// The part of the widget's [`State`] that drives its animation, which gets kept
+/// across widget tree rebuilds for the widgets that were given an id (see [`Widget::id`]).
+#[derive(Clone, Copy)]
+struct Clock {
+    /// See [`State::index`].
+    index: Index,
+    /// See [`State::phase`].
+    phase: f32,
+    /// See [`State::last_tick`].
+    last_tick: std::time::Instant,
+    /// See [`State::progress`].
+    progress: Option<(f32, f32)>,
+    /// See [`State::started`].
+    started: std::time::Instant,
+    /// See [`State::label_time`].
+    label_time: std::time::Duration,
+    /// See [`State::status`].
+    status: Status,
+}
+
+/// The clocks of the widgets that were given an id, as of their latest tick, along
+/// with the moment past which they expire. A widget moving around the widget tree
+/// (or leaving it for a while) picks up where it was, as long as its clock has not
+/// expired.
+#[derive(Default)]
+struct Clocks {
+    /// The recorded clocks, along with the moment past which they expire, by id.
+    entries: std::collections::HashMap<iced_widget::core::widget::Id, (Clock, std::time::Instant)>,
+    /// The moment at which the expired clocks were last dropped, if ever.
+    pruned: Option<std::time::Instant>,
+}
+
+thread_local! {
+    /// See [`Clocks`].
+    static CLOCKS: std::cell::RefCell<Clocks> = Default::default();
+}
+
+/// A private helper function used to record the `clock` of the widget with the
+/// provided `id` at the moment `now`, until `now + retention`. So that the map does
+/// not keep growing with ids that are no longer in use, without going over all of
+/// its entries on every frame, the expired clocks get dropped at most once per
+/// `retention` interval.
+fn record_clock(
+    id: &iced_widget::core::widget::Id,
+    clock: Clock,
+    now: std::time::Instant,
+    retention: std::time::Duration,
+) {
+    CLOCKS.with(|clocks| {
+        let mut clocks = clocks.borrow_mut();
+        let due = clocks
+            .pruned
+            .is_none_or(|pruned| now.saturating_duration_since(pruned) >= retention);
+        if due {
+            clocks.entries.retain(|_, (_, expires)| *expires >= now);
+            clocks.pruned = Some(now);
+        }
+        clocks.entries.insert(id.clone(), (clock, now + retention));
+    });
+}
+
+/// A private helper function that returns the clock last recorded for the widget
+/// with the provided `id`, unless it expired before `now`.
+fn recorded_clock(id: &iced_widget::core::widget::Id, now: std::time::Instant) -> Option<Clock> {
+    CLOCKS.with(|clocks| {
+        clocks
+            .borrow()
+            .entries
+            .get(id)
+            .filter(|(_, expires)| *expires >= now)
+            .map(|(clock, _)| *clock)
+    })
+}
+
 /// A structure used to keep track of the widget's internal state.
 struct State {
     /// The loading indicator's active index.
     index: Index,
+    /// The loading indicator's continuous phase, used with [`Animation::Smooth`].
+    phase: f32,
     /// The moment at which the last tick occurred.
     last_tick: std::time::Instant,
+    /// The displayed and target progress values, used when the widget shows a
+    /// determinate progress, the former trailing the latter with [`Animation::Smooth`].
+    progress: Option<(f32, f32)>,
+    /// The loading indicator's drawing state.
+    cache: DrawCache,
+    /// The moment at which the widget's clock started, i.e. the first tick.
+    started: std::time::Instant,
+    /// The time elapsed between the first and the latest ticks, which drives the [`Label`].
+    label_time: std::time::Duration,
+    /// The widget's status as of the latest tick.
+    status: Status,
+    /// Whether the widget's window is focused.
+    focused: bool,
+    /// Whether the widget's window is minimized (i.e. resized to zero).
+    minimized: bool,
+    /// The id of the widget to which the state belongs, if any.
+    id: Option<iced_widget::core::widget::Id>,
 }
 
-impl Default for State {
-    fn default() -> Self {
+impl State {
+    /// Creates a new [`State`] for a loading indicator made up of `number_of_circles` circles.
+    fn new(number_of_circles: usize) -> Self {
+        let now = std::time::Instant::now();
         Self {
-            index: Default::default(),
-            last_tick: std::time::Instant::now(),
+            index: Index::with_number_of_circles(number_of_circles),
+            phase: 0.0,
+            last_tick: now,
+            progress: None,
+            cache: Default::default(),
+            started: now,
+            label_time: Default::default(),
+            status: Status::Running,
+            focused: true,
+            minimized: false,
+            id: None,
+        }
+    }
+
+    /// Returns the part of the state that drives the animation.
+    fn clock(&self) -> Clock {
+        Clock {
+            index: self.index,
+            phase: self.phase,
+            last_tick: self.last_tick,
+            progress: self.progress,
+            started: self.started,
+            label_time: self.label_time,
+            status: self.status,
         }
     }
+
+    /// Replaces the part of the state that drives the animation with the provided `clock`.
+    fn set_clock(&mut self, clock: Clock) {
+        self.index = clock.index;
+        self.phase = clock.phase;
+        self.last_tick = clock.last_tick;
+        self.progress = clock.progress;
+        self.started = clock.started;
+        self.label_time = clock.label_time;
+        self.status = clock.status;
+    }
+
+    /// Updates the state for the widget's `status` at the moment `now`. Resuming
+    /// a paused widget shifts its clock by the time spent paused, so that the animation
+    /// continues where it was frozen, while restarting a stopped widget resets it.
+    fn set_status(&mut self, status: Status, now: std::time::Instant) {
+        match (self.status, status) {
+            (Status::Paused, Status::Running) => {
+                self.last_tick = now;
+                self.started = now - self.label_time;
+            }
+            (Status::Stopped, Status::Running) => {
+                self.index = Index::with_number_of_circles(self.index.number_of_circles());
+                self.phase = 0.0;
+                self.last_tick = now;
+                self.progress = None;
+                self.started = now;
+                self.label_time = Default::default();
+            }
+            _ => {}
+        }
+        self.status = status;
+    }
 }
 
 /// The loading indicator widget which implements
@@ -239,72 +1216,468 @@ impl Default for State {
 /// convenient wrapper around the [`LoadingIndicator`] type, taking
 /// care of the animation for maximal convenience (i.e. instead of requiring
 /// the user to manually implement the animation himself inside the application).
-pub struct Widget<'a, M> {
-    /// The loading indicator's size in pixels.
-    size: f32,
-    /// The "tick interval" (i.e. animation speed), in milliseconds, used
-    /// by the widget. If not provided, this value will default to [`Self::DEFAULT_TICK_DURATION_MS`].
-    tick_duration_ms: u64,
-    /// A vector of [`iced_widget::canvas::Canvas`] items (containing [`LoadingIndicator`]s)
-    /// converted into [`iced_widget::core::Element`]s.
-    content: Vec<iced_widget::core::Element<'a, M, Renderer>>,
+///
+/// The widget draws a single [`LoadingIndicator`] for its current index (or phase),
+/// reusing the tessellated geometry kept in its state (see [`DrawCache`]) for as long
+/// as its appearance does not change.
+pub struct Widget<'a, M, Renderer = iced_widget::renderer::Renderer<iced_style::Theme>>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The widget's configuration.
+    config: Config<Renderer::Theme>,
+    /// The determinate progress to be shown, if any (see [`Widget::progress`]).
+    progress: Option<f32>,
+    /// Whether the indicator gets colored with its parent's text color.
+    inherit_text_color: bool,
+    /// The caption shown next to the indicator, if any.
+    label: Option<Label>,
+    /// Whether the widget is running (see [`Widget::running`]).
+    running: bool,
+    /// Whether the widget is paused (see [`Widget::paused`]).
+    paused: bool,
+    /// The widget's id, if any (see [`Widget::id`]).
+    id: Option<iced_widget::core::widget::Id>,
+    /// Binds the widget's type parameters, which are otherwise only used by its trait implementations.
+    _marker: std::marker::PhantomData<(&'a (), M, Renderer)>,
 }
 
-impl<'a, M> Widget<'a, M> {
+impl<'a, M, Renderer> Widget<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
     /// The default "tick interval" (i.e. animation speed), in milliseconds, used
     /// by the widget if none gets specified.
-    pub const DEFAULT_TICK_DURATION_MS: u64 = 80;
-}
+    pub const DEFAULT_TICK_DURATION_MS: u64 = DEFAULT_TICK_DURATION_MS;
 
-impl<'a, M> Widget<'a, M>
-where
-    M: 'a + Clone,
-{
-    /// The factory method that must be used to instantiate the widget.
-    ///
-    /// **Parameters**:
-    /// * `size`: The indicator's size in pixels.
-    /// * `style`: An optional value containing the [`LoadingIndicator`]'s style to be used.
-    /// * `lighter_inactive`: A boolean value indicating whether the [`LoadingIndicator`] should
-    /// use a `0.1` alpha channel (`false`) or a `0.025` alpha channel (`true`) for the background
-    /// in inactive circle indexes. The latter comes out better in darker themed apps, while the
-    /// former is better for lighter themed apps.
-    pub fn new(size: f32, style: Option<Style>, lighter_inactive: bool) -> Self {
-        let content = (0usize..NUMBER_OF_CIRCLES)
-            .into_iter()
-            .map(|index| {
-                LoadingIndicator::new(size, Index(index))
-                    .style(style.unwrap_or(Default::default()))
-                    .lighter_inactive(lighter_inactive)
-                    .into()
-            })
-            .collect();
+    /// A factory method that can be used to instantiate the widget with the
+    /// default settings (see [`Config::new`]), which can then be customized
+    /// using the widget's setter methods.
+    pub fn new() -> Self {
+        Self::from_config(Default::default())
+    }
+
+    /// A factory method that can be used to instantiate the widget with the
+    /// specified `size` (in pixels) and the default settings otherwise.
+    pub fn with_size(size: f32) -> Self {
+        Self::new().size(size)
+    }
+
+    /// A factory method that can be used to instantiate the widget from
+    /// an existing [`Config`].
+    pub fn from_config(config: Config<Renderer::Theme>) -> Self {
         Self {
-            size,
-            content,
-            tick_duration_ms: Self::DEFAULT_TICK_DURATION_MS,
+            config,
+            progress: None,
+            inherit_text_color: false,
+            label: None,
+            running: true,
+            paused: false,
+            id: None,
+            _marker: Default::default(),
+        }
+    }
+
+    /// A private helper method that returns the [`LoadingIndicator`] to be drawn
+    /// for the provided widget `state`.
+    fn indicator(&self, state: &State) -> LoadingIndicator<Renderer::Theme> {
+        let indicator = self.config.loading_indicator(state.index);
+        if let Some(progress) = self.progress {
+            return match (self.config.animation, state.progress) {
+                (Animation::Smooth, Some((displayed, _))) => indicator.progress(displayed),
+                _ => indicator.progress(progress),
+            };
+        }
+        match self.config.animation {
+            Animation::Discrete => indicator,
+            Animation::Smooth => indicator.phase(state.phase),
         }
     }
 
+    /// A private helper method that advances the animation kept in the widget `state`
+    /// for a redraw requested at the moment `now`, and requests the next redraw through
+    /// the `shell`, unless the widget, laid out within `bounds`, is not in the `viewport`.
+    fn on_redraw(
+        &self,
+        state: &mut State,
+        now: std::time::Instant,
+        bounds: iced_widget::core::Rectangle,
+        viewport: &iced_widget::core::Rectangle,
+        shell: &mut iced_widget::core::Shell<'_, M>,
+    ) {
+        state.set_status(self.status(), now);
+        if state.status != Status::Running {
+            // The state stays as is, and no redraw gets requested.
+            return;
+        }
+
+        if !bounds.intersects(viewport) {
+            // The widget being off-screen (e.g. scrolled out of a `Scrollable`), no
+            // redraw gets requested. Since the last tick is kept as is, the animation
+            // catches up with the elapsed time once the widget is back in view.
+            return;
+        }
+
+        // Like an off-screen widget, a hidden one keeps its clock going.
+        let throttle = match (state.minimized, state.focused, self.config.unfocused) {
+            (true, _, _) | (false, false, Unfocused::Pause) => {
+                return;
+            }
+            (false, false, Unfocused::Throttle(interval)) => Some(interval),
+            _ => None,
+        };
+        let mut request_redraw = |request| shell.request_redraw(throttled(request, now, throttle));
+
+        let delay = self.config.tick_duration();
+        let elapsed = now.duration_since(state.last_tick);
+
+        if let Some(label) = &self.label {
+            state.label_time = now.duration_since(state.started);
+            match label.next_redraw(state.label_time, self.revolution()) {
+                Some(wait) if wait.is_zero() => {
+                    request_redraw(iced_widget::core::window::RedrawRequest::NextFrame)
+                }
+                Some(wait) => {
+                    request_redraw(iced_widget::core::window::RedrawRequest::At(now + wait))
+                }
+                None => {}
+            }
+        }
+
+        if let Some(target) = self.progress {
+            let (displayed, previous_target) = state.progress.unwrap_or((target, target));
+            let displayed = match self.config.animation {
+                // Easing only starts once the target changes, so that the time spent
+                // idle with a settled value does not count as elapsed.
+                Animation::Smooth if displayed != previous_target => {
+                    let time_constant = delay.as_secs_f32().max(f32::EPSILON);
+                    let step = 1.0 - (-elapsed.as_secs_f32() / time_constant).exp();
+                    displayed + (target - displayed) * step
+                }
+                Animation::Smooth => displayed,
+                Animation::Discrete => target,
+            };
+            let displayed = if (target - displayed).abs() < PROGRESS_EPSILON {
+                target
+            } else {
+                displayed
+            };
+            state.progress = Some((displayed, target));
+            state.last_tick = now;
+            if displayed != target {
+                request_redraw(iced_widget::core::window::RedrawRequest::NextFrame);
+            }
+        } else if self.config.synchronized {
+            // The index and phase derive from the common epoch rather than from the
+            // widget's own clock, so that synchronized widgets line up exactly.
+            let since_epoch = now.saturating_duration_since(epoch());
+            let number_of_circles = self.config.number_of_circles;
+            let ticks = since_epoch.as_nanos() / delay.as_nanos().max(1);
+            state.index = Index {
+                value: (ticks % number_of_circles as u128) as usize,
+                number_of_circles,
+            };
+            state.last_tick = now;
+            match self.config.animation {
+                Animation::Smooth => {
+                    let period = delay.as_secs_f64() * number_of_circles as f64;
+                    if period > 0.0 {
+                        state.phase = (since_epoch.as_secs_f64() / period).fract() as f32;
+                    }
+                    request_redraw(iced_widget::core::window::RedrawRequest::NextFrame);
+                }
+                Animation::Discrete => {
+                    state.phase = state.index.value() as f32 / number_of_circles as f32;
+                    let next_tick = std::time::Duration::from_nanos(
+                        ((ticks + 1) * delay.as_nanos().max(1)) as u64,
+                    );
+                    request_redraw(iced_widget::core::window::RedrawRequest::At(
+                        epoch() + next_tick,
+                    ));
+                }
+            }
+        } else if let Animation::Smooth = self.config.animation {
+            let period = delay.as_secs_f32() * (self.config.number_of_circles as f32);
+            if period > 0.0 {
+                state.phase = (state.phase + elapsed.as_secs_f32() / period).fract();
+            }
+            state.index = Index {
+                value: (state.phase * (self.config.number_of_circles as f32)) as usize
+                    % self.config.number_of_circles,
+                number_of_circles: self.config.number_of_circles,
+            };
+            state.last_tick = now;
+            request_redraw(iced_widget::core::window::RedrawRequest::NextFrame);
+        } else if elapsed > delay {
+            if state.index.number_of_circles() != self.config.number_of_circles {
+                // The number of circles changed since the state was created.
+                state.index = Index::with_number_of_circles(self.config.number_of_circles);
+            }
+            // More than one tick may have elapsed since the last one, e.g. while
+            // the widget was off-screen.
+            let ticks = elapsed.as_nanos() / delay.as_nanos().max(1);
+            for _ in 0..ticks % state.index.number_of_circles() as u128 {
+                state.index.tick();
+            }
+            state.phase = (state.index.value() as f32) / (state.index.number_of_circles() as f32);
+            // The clock only moves by whole ticks, so that the time left over below one
+            // tick carries over to the next one instead of getting lost.
+            let ticks = u32::try_from(ticks).unwrap_or(u32::MAX);
+            state.last_tick = delay
+                .checked_mul(ticks)
+                .and_then(|advance| state.last_tick.checked_add(advance))
+                .map_or(now, |last_tick| last_tick.min(now));
+            request_redraw(iced_widget::core::window::RedrawRequest::At(
+                state.last_tick + delay,
+            ));
+        } else {
+            let remaining = delay - elapsed;
+            request_redraw(iced_widget::core::window::RedrawRequest::At(
+                now + remaining,
+            ))
+        }
+    }
+
+    /// A private helper method that returns a new [`State`] for the widget, which
+    /// picks up the clock that was last recorded for its id, if any.
+    fn new_state(&self) -> State {
+        let mut state = State::new(self.config.number_of_circles);
+        if let Some(id) = &self.id {
+            if let Some(clock) = recorded_clock(id, std::time::Instant::now()) {
+                state.set_clock(clock);
+            }
+            state.id = Some(id.clone());
+        }
+        state
+    }
+
+    /// A private helper method that returns the widget's [`Status`].
+    fn status(&self) -> Status {
+        match (self.running, self.paused) {
+            (false, _) => Status::Stopped,
+            (true, true) => Status::Paused,
+            (true, false) => Status::Running,
+        }
+    }
+
+    /// A private helper method that returns the duration of one of the indicator's
+    /// revolutions (i.e. as many ticks as there are circles).
+    fn revolution(&self) -> std::time::Duration {
+        self.config.tick_duration() * self.config.number_of_circles as u32
+    }
+
+    /// A private helper method that applies `f` to the widget's configuration.
+    fn map_config(
+        self,
+        f: impl FnOnce(Config<Renderer::Theme>) -> Config<Renderer::Theme>,
+    ) -> Self {
+        Self {
+            config: f(self.config),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the indicator's size (in pixels),
+    /// which is the height of a [`Kind::Bar`] indicator.
+    pub fn size(self, value: f32) -> Self {
+        self.map_config(|config| config.size(value))
+    }
+
+    /// A setter method that can be used to specify the style (e.g. [`Style`] for
+    /// the built-in theme). See [`LoadingIndicator::style`].
+    pub fn style(self, value: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.map_config(|config| config.style(value))
+    }
+
+    /// A setter method that can be used to specify whether a lighter alpha channel
+    /// value (`0.025` instead of `0.1`) should be used for the inactive circles. The
+    /// former comes out better in darker themed apps, while the latter is better for
+    /// lighter themed apps. See [`Trail::lighter_inactive`].
+    pub fn lighter_inactive(self, value: bool) -> Self {
+        self.map_config(|config| config.lighter_inactive(value))
+    }
+
+    /// A setter method that can be used to specify how the alpha channel of the
+    /// inactive circles gets determined. With [`Inactive::Auto`], the value follows
+    /// the theme's background, including when the theme changes at runtime.
+    /// See [`Trail::inactive`].
+    pub fn inactive(self, value: Inactive) -> Self {
+        self.map_config(|config| config.inactive(value))
+    }
+
+    /// A setter method that can be used to specify the number of circles from
+    /// which the loading indicator is made up (defaults to [`DEFAULT_NUMBER_OF_CIRCLES`]).
+    /// See [`LoadingIndicator::number_of_circles`].
+    ///
+    /// **Panics** if `value` is zero.
+    pub fn number_of_circles(self, value: usize) -> Self {
+        self.map_config(|config| config.number_of_circles(value))
+    }
+
+    /// A setter method that can be used to specify the [`Trail`] following
+    /// the active circle. See [`LoadingIndicator::trail`].
+    pub fn trail(self, value: Trail) -> Self {
+        self.map_config(|config| config.trail(value))
+    }
+
     /// A setter method that can be used to specify the animation speed (in milliseconds).
     pub fn tick_duration_ms(self, value: u64) -> Self {
+        self.map_config(|config| config.tick_duration_ms(value))
+    }
+
+    /// A setter method that can be used to specify the animation speed as
+    /// the duration of a single tick. See [`Config::speed`].
+    pub fn speed(self, value: std::time::Duration) -> Self {
+        self.map_config(|config| config.speed(value))
+    }
+
+    /// A setter method that can be used to specify the [`Animation`] mode
+    /// (defaults to [`Animation::Discrete`]). With [`Animation::Smooth`], the
+    /// widget requests a redraw at every frame and a full revolution still takes
+    /// "number of circles" times the tick duration.
+    pub fn animation(self, value: Animation) -> Self {
+        self.map_config(|config| config.animation(value))
+    }
+
+    /// A setter method that can be used to specify how the widget gets animated
+    /// while its window is unfocused (defaults to [`Unfocused::Animate`]).
+    pub fn unfocused(self, value: Unfocused) -> Self {
+        self.map_config(|config| config.unfocused(value))
+    }
+
+    /// A setter method that can be used to specify whether the widget's animation
+    /// derives from an epoch shared by all synchronized widgets (instead of from the
+    /// moment at which the widget was created), so that synchronized widgets with the
+    /// same speed and number of circles line up exactly, whenever they were created.
+    /// A synchronized widget being paused and resumed jumps back in line.
+    pub fn synchronized(self, value: bool) -> Self {
+        self.map_config(|config| config.synchronized(value))
+    }
+
+    /// A setter method that can be used to specify the [`Kind`] of loading
+    /// indicator to be drawn (defaults to [`Kind::Dots`]).
+    pub fn kind(self, value: Kind) -> Self {
+        self.map_config(|config| config.kind(value))
+    }
+
+    /// A setter method that can be used to specify the [`Shape`] of the circles
+    /// of a [`Kind::Dots`] loading indicator (defaults to [`Shape::Circle`]).
+    pub fn shape(self, value: Shape) -> Self {
+        self.map_config(|config| config.shape(value))
+    }
+
+    /// A setter method that turns the widget into a determinate progress indicator
+    /// showing the provided `value` (see [`LoadingIndicator::progress`]), which is meant
+    /// to be fed by the application at each `view`. The widget stops spinning and, with
+    /// [`Animation::Smooth`], eases towards each new value instead of jumping to it.
+    pub fn progress(self, value: f32) -> Self {
         Self {
-            tick_duration_ms: value,
+            progress: Some(value.clamp(0.0, 1.0)),
             ..self
         }
     }
+
+    /// A setter method that can be used to give the widget a stable `id` (e.g.
+    /// `iced_widget::core::widget::Id::new(format!("row-{}", key))`), so that its
+    /// animation survives the widget tree being restructured (e.g. a list row moving),
+    /// instead of restarting whenever the widget ends up with a fresh state or with
+    /// the state of another widget.
+    ///
+    /// The widget's clock gets kept outside of the widget tree, for a few revolutions
+    /// after the widget was last redrawn, so that ids that are no longer in use (e.g.
+    /// those of removed rows, or ones created with `Id::unique`) do not accumulate.
+    /// The trade-off is that a widget which leaves the tree for longer than that
+    /// restarts once back.
+    pub fn id(self, value: iced_widget::core::widget::Id) -> Self {
+        Self {
+            id: Some(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to pause the widget, which then freezes on its
+    /// current frame and stops requesting redraws, so that an idle application does not
+    /// use any CPU. Once resumed, the animation continues from where it was frozen.
+    pub fn paused(self, value: bool) -> Self {
+        Self {
+            paused: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to stop the widget (when `false`), which then
+    /// keeps its space in the layout, but neither draws anything nor requests redraws.
+    /// Once running again, the animation starts over.
+    pub fn running(self, value: bool) -> Self {
+        Self {
+            running: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify whether the indicator's active color
+    /// should be the text color inherited from its parent widget (e.g. a button's text
+    /// color) instead of the one resolved from its style. The trail and the inactive
+    /// circles derive from that color as usual.
+    pub fn inherit_text_color(self, value: bool) -> Self {
+        Self {
+            inherit_text_color: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify a caption (e.g. "Loading…") shown next
+    /// to the indicator, either as plain text (placed below the indicator) or as a [`Label`].
+    pub fn label(self, value: impl Into<Label>) -> Self {
+        Self {
+            label: Some(value.into()),
+            ..self
+        }
+    }
+}
+
+impl<'a, M, Renderer> Default for Widget<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl<'a, M> iced_widget::core::Widget<M, Renderer> for Widget<'a, M>
+impl<'a, M, Renderer> std::convert::From<Config<Renderer::Theme>> for Widget<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: Config<Renderer::Theme>) -> Self {
+        Self::from_config(value)
+    }
+}
+
+impl<'a, M, Renderer> iced_widget::core::Widget<M, Renderer> for Widget<'a, M, Renderer>
 where
     M: 'a + Clone,
+    Renderer: 'a + iced_widget::canvas::Renderer + iced_widget::core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+    LoadingIndicator<Renderer::Theme>: iced_widget::canvas::Program<M, Renderer, State = DrawCache>,
 {
     fn width(&self) -> iced_widget::core::Length {
-        iced_widget::core::Length::Fixed(self.size)
+        match (&self.label, self.config.kind.width(self.config.size)) {
+            // The label's width is only known once laid out.
+            (Some(_), iced_widget::core::Length::Fixed(_)) => iced_widget::core::Length::Shrink,
+            (_, width) => width,
+        }
     }
 
     fn height(&self) -> iced_widget::core::Length {
-        iced_widget::core::Length::Fixed(self.size)
+        match (&self.label, self.config.kind.height(self.config.size)) {
+            (Some(_), iced_widget::core::Length::Fixed(_)) => iced_widget::core::Length::Shrink,
+            (_, height) => height,
+        }
     }
 
     fn tag(&self) -> iced_widget::core::widget::tree::Tag {
@@ -312,14 +1685,26 @@ where
     }
 
     fn state(&self) -> iced_widget::core::widget::tree::State {
-        iced_widget::core::widget::tree::State::new(State::default())
+        iced_widget::core::widget::tree::State::new(self.new_state())
     }
 
-    fn children(&self) -> Vec<iced_widget::core::widget::Tree> {
-        self.content
-            .iter()
-            .map(iced_widget::core::widget::tree::Tree::new)
-            .collect()
+    fn diff(&self, tree: &mut iced_widget::core::widget::Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.id != self.id {
+            // The state belongs to another widget, e.g. after list rows were reordered.
+            *state = self.new_state();
+        }
+
+        // Other settings changes (e.g. speed or style) keep the state as is, while
+        // the index gets adjusted to a new number of circles, at the same phase.
+        let number_of_circles = self.config.number_of_circles;
+        if state.index.number_of_circles() != number_of_circles {
+            state.index = Index {
+                value: (state.phase * number_of_circles as f32) as usize % number_of_circles,
+                number_of_circles,
+            };
+        }
     }
 
     fn draw(
@@ -330,59 +1715,115 @@ where
         style: &iced_widget::core::renderer::Style,
         layout: iced_widget::core::Layout<'_>,
         cursor: iced_widget::core::mouse::Cursor,
-        viewport: &iced_widget::core::Rectangle,
+        _viewport: &iced_widget::core::Rectangle,
     ) {
+        if !self.running {
+            return;
+        }
+
         let state = tree.state.downcast_ref::<State>();
-        self.content[state.index.0].as_widget().draw(
-            &tree.children[state.index.0],
-            renderer,
-            theme,
-            style,
-            layout,
-            cursor,
-            viewport,
+        let indicator = self.indicator(state);
+        let indicator = if self.inherit_text_color {
+            indicator.text_color(style.text_color)
+        } else {
+            indicator
+        };
+        // With a label, the indicator and the label are laid out as children.
+        let mut children = layout.children();
+        let bounds = children.next().unwrap_or(layout).bounds();
+
+        if let (Some(label), Some(label_layout)) = (&self.label, children.next()) {
+            let color = if self.inherit_text_color {
+                style.text_color
+            } else {
+                theme.appearance(&self.config.style).color
+            };
+            label.draw(
+                renderer,
+                style,
+                label_layout,
+                color,
+                state.label_time,
+                self.revolution(),
+            );
+        }
+
+        renderer.with_translation(
+            iced_widget::core::Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                let geometry = iced_widget::canvas::Program::<M, Renderer>::draw(
+                    &indicator,
+                    &state.cache,
+                    renderer,
+                    theme,
+                    bounds,
+                    cursor,
+                );
+                renderer.draw(geometry);
+            },
         );
     }
 
     fn layout(
         &self,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &iced_widget::core::layout::Limits,
     ) -> iced_widget::core::layout::Node {
-        let limits = limits.width(self.size).height(self.size);
-        let size = limits.resolve(iced_widget::core::Size::ZERO);
-        iced_widget::core::layout::Node::new(size)
+        let layout_indicator = |limits: &iced_widget::core::layout::Limits| {
+            let limits = limits
+                .width(self.config.kind.width(self.config.size))
+                .height(self.config.kind.height(self.config.size));
+            // Tighter limits shrink the widget, which keeps the drawn indicator's aspect ratio.
+            let size = self
+                .config
+                .kind
+                .fit(limits.resolve(iced_widget::core::Size::ZERO));
+            iced_widget::core::layout::Node::new(size)
+        };
+
+        match &self.label {
+            Some(label) => label.layout(renderer, limits, layout_indicator),
+            None => layout_indicator(limits),
+        }
     }
 
     fn on_event(
         &mut self,
         tree: &mut iced_widget::core::widget::Tree,
         event: iced_widget::core::Event,
-        _layout: iced_widget::core::Layout<'_>,
+        layout: iced_widget::core::Layout<'_>,
         _cursor: iced_widget::core::mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn iced_widget::core::Clipboard,
         shell: &mut iced_widget::core::Shell<'_, M>,
-        _viewport: &iced_widget::core::Rectangle,
+        viewport: &iced_widget::core::Rectangle,
     ) -> iced_widget::core::event::Status {
         let state = tree.state.downcast_mut::<State>();
 
+        match event {
+            iced_widget::core::Event::Window(iced_widget::core::window::Event::Focused) => {
+                state.focused = true;
+            }
+            iced_widget::core::Event::Window(iced_widget::core::window::Event::Unfocused) => {
+                state.focused = false;
+            }
+            iced_widget::core::Event::Window(iced_widget::core::window::Event::Resized {
+                width,
+                height,
+            }) => {
+                state.minimized = width == 0 || height == 0;
+            }
+            _ => {}
+        }
+
         if let iced_widget::core::Event::Window(
             iced_widget::core::window::Event::RedrawRequested(now),
         ) = event
         {
-            let delay = std::time::Duration::from_millis(self.tick_duration_ms);
-            let elapsed = now.duration_since(state.last_tick);
-
-            if elapsed > delay {
-                state.index.tick();
-                state.last_tick = now;
-                shell.request_redraw(iced_widget::core::window::RedrawRequest::At(now + delay));
-            } else {
-                let remaining = delay - elapsed;
-                shell.request_redraw(iced_widget::core::window::RedrawRequest::At(
-                    now + remaining,
-                ))
+            self.on_redraw(state, now, layout.bounds(), viewport, shell);
+            if let Some(id) = &self.id {
+                let retention = self.revolution() * CLOCK_RETENTION_REVOLUTIONS;
+                record_clock(id, state.clock(), now, retention);
             }
         }
 
@@ -390,11 +1831,187 @@ where
     }
 }
 
-impl<'a, M> std::convert::From<Widget<'a, M>> for iced_widget::core::Element<'a, M, Renderer>
+impl<'a, M, Renderer> std::convert::From<Widget<'a, M, Renderer>>
+    for iced_widget::core::Element<'a, M, Renderer>
 where
     M: 'a + Clone,
+    Renderer: 'a + iced_widget::canvas::Renderer + iced_widget::core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+    LoadingIndicator<Renderer::Theme>: iced_widget::canvas::Program<M, Renderer, State = DrawCache>,
 {
-    fn from(value: Widget<'a, M>) -> Self {
+    fn from(value: Widget<'a, M, Renderer>) -> Self {
         Self::new(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Renderer = iced_widget::renderer::Renderer<iced_style::Theme>;
+
+    /// Returns a headless `tiny-skia` renderer.
+    fn renderer() -> Renderer {
+        Renderer::TinySkia(iced_tiny_skia::Renderer::new(iced_tiny_skia::Backend::new(
+            Default::default(),
+        )))
+    }
+
+    /// Returns the widget tree of a default widget.
+    fn tree() -> iced_widget::core::widget::Tree {
+        let element: iced_widget::core::Element<'static, (), Renderer> = Widget::new().into();
+        iced_widget::core::widget::Tree::new(element.as_widget())
+    }
+
+    /// Diffs the `tree` with the provided `widget` and sends it the provided `event`,
+    /// returning the widget's index, phase and redraw request.
+    fn send(
+        widget: Widget<'static, (), Renderer>,
+        tree: &mut iced_widget::core::widget::Tree,
+        event: iced_widget::core::Event,
+    ) -> (usize, f32, Option<iced_widget::core::window::RedrawRequest>) {
+        let renderer = renderer();
+        let mut element: iced_widget::core::Element<'static, (), Renderer> = widget.into();
+        tree.diff(element.as_widget());
+        let node = element
+            .as_widget()
+            .layout(&renderer, &iced_widget::core::layout::Limits::NONE);
+        let layout = iced_widget::core::Layout::new(&node);
+        let mut messages = Vec::new();
+        let mut shell = iced_widget::core::Shell::new(&mut messages);
+        let _ = element.as_widget_mut().on_event(
+            tree,
+            event,
+            layout,
+            iced_widget::core::mouse::Cursor::Unavailable,
+            &renderer,
+            &mut iced_widget::core::clipboard::Null,
+            &mut shell,
+            &layout.bounds(),
+        );
+        let redraw_request = shell.redraw_request();
+        let state = tree.state.downcast_ref::<State>();
+        (state.index.value(), state.phase, redraw_request)
+    }
+
+    /// Diffs the `tree` with the provided `widget` and sends it a redraw requested at
+    /// the moment `now`, returning the widget's index, phase and redraw request.
+    fn redraw(
+        widget: Widget<'static, (), Renderer>,
+        tree: &mut iced_widget::core::widget::Tree,
+        now: std::time::Instant,
+    ) -> (usize, f32, Option<iced_widget::core::window::RedrawRequest>) {
+        send(
+            widget,
+            tree,
+            iced_widget::core::Event::Window(iced_widget::core::window::Event::RedrawRequested(
+                now,
+            )),
+        )
+    }
+
+    #[test]
+    fn paused_widget_freezes_and_resumes_where_it_was() {
+        let mut tree = tree();
+        let tick = std::time::Duration::from_millis(DEFAULT_TICK_DURATION_MS);
+        let start = std::time::Instant::now();
+
+        let (index, phase, _) = redraw(Widget::new(), &mut tree, start + tick * 3);
+        assert_eq!(index, 3);
+
+        // While paused, the state stays as is and no redraw gets requested.
+        let now = start + std::time::Duration::from_secs(10);
+        let frozen = redraw(Widget::new().paused(true), &mut tree, now);
+        assert_eq!(frozen, (index, phase, None));
+
+        // Once resumed, the animation continues from the frozen index.
+        let (resumed, _, request) = redraw(Widget::new(), &mut tree, now + tick / 2);
+        assert_eq!(resumed, index);
+        assert!(request.is_some());
+        let (next, _, _) = redraw(Widget::new(), &mut tree, now + tick / 2 + tick * 2);
+        assert_eq!(next, index + 2);
+    }
+
+    #[test]
+    fn stopped_widget_restarts_from_zero() {
+        let mut tree = tree();
+        let tick = std::time::Duration::from_millis(DEFAULT_TICK_DURATION_MS);
+        let start = std::time::Instant::now();
+
+        let (index, _, _) = redraw(Widget::new(), &mut tree, start + tick * 3);
+        assert_eq!(index, 3);
+
+        let now = start + std::time::Duration::from_secs(10);
+        let (_, _, request) = redraw(Widget::new().running(false), &mut tree, now);
+        assert_eq!(request, None);
+
+        let (index, phase, _) = redraw(Widget::new().running(true), &mut tree, now + tick);
+        assert_eq!((index, phase), (0, 0.0));
+    }
+
+    #[test]
+    fn throttled_widget_keeps_up_with_whole_ticks() {
+        let mut tree = tree();
+        let tick = std::time::Duration::from_millis(DEFAULT_TICK_DURATION_MS);
+        let throttle = std::time::Duration::from_millis(500);
+        let widget = || Widget::new().unfocused(Unfocused::Throttle(throttle));
+        let start = std::time::Instant::now();
+
+        let _ = send(
+            widget(),
+            &mut tree,
+            iced_widget::core::Event::Window(iced_widget::core::window::Event::Unfocused),
+        );
+
+        // Each throttled frame covers 6.25 ticks, whose remainders add up to a
+        // whole tick by the fourth frame.
+        let mut index = 0;
+        for frame in 1..=4 {
+            let now = start + throttle * frame;
+            let (value, _, request) = redraw(widget(), &mut tree, now);
+            assert_eq!(
+                request,
+                Some(iced_widget::core::window::RedrawRequest::At(now + throttle))
+            );
+            index = value;
+        }
+        let ticks = (throttle * 4).as_nanos() / tick.as_nanos();
+        assert_eq!(index, ticks as usize % DEFAULT_NUMBER_OF_CIRCLES);
+    }
+
+    #[test]
+    fn widgets_with_ids_keep_their_index_when_swapped() {
+        let (mut first, mut second) = (tree(), tree());
+        let tick = std::time::Duration::from_millis(DEFAULT_TICK_DURATION_MS);
+        let start = std::time::Instant::now();
+        let a = || Widget::new().id(iced_widget::core::widget::Id::new("row-a"));
+        let b = || Widget::new().id(iced_widget::core::widget::Id::new("row-b"));
+
+        // The states get created on the first redraw, i.e. slightly after `start`.
+        let (at_a, at_b) = (start + tick * 5 + tick / 2, start + tick * 3 + tick / 2);
+        let (index_a, _, _) = redraw(a(), &mut first, at_a);
+        let (index_b, _, _) = redraw(b(), &mut second, at_b);
+        assert_eq!((index_a, index_b), (5, 3));
+
+        // The rows swap positions, so that each tree now holds the other row's state.
+        let (swapped_b, _, _) = redraw(b(), &mut first, at_b);
+        let (swapped_a, _, _) = redraw(a(), &mut second, at_a);
+        assert_eq!((swapped_a, swapped_b), (index_a, index_b));
+    }
+
+    #[test]
+    fn settings_changes_keep_the_state() {
+        let mut tree = tree();
+        let tick = std::time::Duration::from_millis(DEFAULT_TICK_DURATION_MS);
+        let now = std::time::Instant::now() + tick * 3;
+
+        let (index, phase, _) = redraw(Widget::new(), &mut tree, now);
+        assert_eq!(index, 3);
+
+        let changed = Widget::new()
+            .tick_duration_ms(DEFAULT_TICK_DURATION_MS * 2)
+            .style(Style::PrimaryColor);
+        assert_eq!(redraw(changed, &mut tree, now).0, index);
+        assert_eq!(tree.state.downcast_ref::<State>().phase, phase);
+    }
+}
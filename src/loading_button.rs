@@ -0,0 +1,324 @@
+//! Show a loading indicator inside a button while it is busy.
+
+use crate::{DrawCache, LoadingIndicator, StyleSheet, Widget};
+
+/// A button that, while loading, shows a loading indicator [`Widget`] in place
+/// of its content.
+///
+/// The content still gets laid out while loading, so that the button keeps its
+/// size, and the indicator gets constrained to the content's height (e.g. a label's
+/// text height) and centered within it. It gets colored with the button's text color
+/// (see [`Widget::inherit_text_color`]). While loading, the button is disabled, so
+/// that presses do not trigger its `on_press` message, and it gets drawn with its
+/// style's disabled appearance.
+///
+/// ## Example
+/// ```
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Submit,
+/// }
+///
+/// let is_submitting = true;
+/// let button: iced_loading_indicator::LoadingButton<'_, Message> =
+///     iced_loading_indicator::LoadingButton::new(iced_widget::text("Submit"))
+///         .on_press(Message::Submit)
+///         .loading(is_submitting);
+/// ```
+pub struct LoadingButton<'a, M, Renderer = iced_widget::renderer::Renderer<iced_style::Theme>>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet + iced_widget::button::StyleSheet,
+{
+    /// The button's content.
+    content: iced_widget::core::Element<'a, M, Renderer>,
+    /// The loading indicator shown in place of the content while loading.
+    indicator: Widget<'a, M, Renderer>,
+    /// Whether the button is loading.
+    is_loading: bool,
+    /// The message produced when the button gets pressed, if any.
+    on_press: Option<M>,
+    /// The button's width.
+    width: iced_widget::core::Length,
+    /// The button's height.
+    height: iced_widget::core::Length,
+    /// The button's padding.
+    padding: Option<iced_widget::core::Padding>,
+    /// The button's style.
+    style: <Renderer::Theme as iced_widget::button::StyleSheet>::Style,
+}
+
+impl<'a, M, Renderer> LoadingButton<'a, M, Renderer>
+where
+    M: 'a + Clone,
+    Renderer: 'a + iced_widget::canvas::Renderer + iced_widget::core::text::Renderer,
+    Renderer::Theme: StyleSheet + iced_widget::button::StyleSheet,
+    LoadingIndicator<Renderer::Theme>: iced_widget::canvas::Program<M, Renderer, State = DrawCache>,
+{
+    /// A factory method that returns a button showing the provided `content`, using
+    /// the default loading indicator [`Widget`] while loading.
+    pub fn new(content: impl Into<iced_widget::core::Element<'a, M, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            indicator: Widget::new(),
+            is_loading: false,
+            on_press: None,
+            width: iced_widget::core::Length::Shrink,
+            height: iced_widget::core::Length::Shrink,
+            padding: None,
+            style: Default::default(),
+        }
+    }
+
+    /// A setter method that can be used to specify whether the button is loading.
+    pub fn loading(self, value: bool) -> Self {
+        Self {
+            is_loading: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the loading indicator [`Widget`]
+    /// shown in place of the content while loading, whose size acts as an upper bound.
+    pub fn indicator(self, value: Widget<'a, M, Renderer>) -> Self {
+        Self {
+            indicator: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the message produced when
+    /// the button gets pressed (while not loading).
+    pub fn on_press(self, value: M) -> Self {
+        Self {
+            on_press: Some(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the button's width.
+    pub fn width(self, value: impl Into<iced_widget::core::Length>) -> Self {
+        Self {
+            width: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the button's height.
+    pub fn height(self, value: impl Into<iced_widget::core::Length>) -> Self {
+        Self {
+            height: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the button's padding.
+    pub fn padding(self, value: impl Into<iced_widget::core::Padding>) -> Self {
+        Self {
+            padding: Some(value.into()),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the button's style.
+    pub fn style(
+        self,
+        value: impl Into<<Renderer::Theme as iced_widget::button::StyleSheet>::Style>,
+    ) -> Self {
+        Self {
+            style: value.into(),
+            ..self
+        }
+    }
+}
+
+/// The content of a [`LoadingButton`], which lays out the button's content and,
+/// while loading, draws the loading indicator in its place.
+struct Content<'a, M, Renderer> {
+    /// The button's content.
+    content: iced_widget::core::Element<'a, M, Renderer>,
+    /// The loading indicator.
+    indicator: iced_widget::core::Element<'a, M, Renderer>,
+    /// Whether the button is loading.
+    is_loading: bool,
+}
+
+impl<'a, M, Renderer> iced_widget::core::Widget<M, Renderer> for Content<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+{
+    fn children(&self) -> Vec<iced_widget::core::widget::Tree> {
+        crate::centered::children(&self.content, &self.indicator)
+    }
+
+    fn diff(&self, tree: &mut iced_widget::core::widget::Tree) {
+        crate::centered::diff(tree, &self.content, &self.indicator);
+    }
+
+    fn width(&self) -> iced_widget::core::Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> iced_widget::core::Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_widget::core::layout::Limits,
+    ) -> iced_widget::core::layout::Node {
+        crate::centered::layout(&self.content, &self.indicator, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut iced_widget::core::widget::Tree,
+        layout: iced_widget::core::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn iced_widget::core::widget::Operation<M>,
+    ) {
+        crate::centered::operate(&self.content, tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut iced_widget::core::widget::Tree,
+        event: iced_widget::core::Event,
+        layout: iced_widget::core::Layout<'_>,
+        cursor: iced_widget::core::mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_widget::core::Clipboard,
+        shell: &mut iced_widget::core::Shell<'_, M>,
+        viewport: &iced_widget::core::Rectangle,
+    ) -> iced_widget::core::event::Status {
+        let mut children = layout.children();
+        let (content_layout, indicator_layout) =
+            (children.next().unwrap(), children.next().unwrap());
+
+        if !self.is_loading {
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        self.indicator.as_widget_mut().on_event(
+            &mut tree.children[1],
+            event,
+            indicator_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &iced_widget::core::widget::Tree,
+        layout: iced_widget::core::Layout<'_>,
+        cursor: iced_widget::core::mouse::Cursor,
+        viewport: &iced_widget::core::Rectangle,
+        renderer: &Renderer,
+    ) -> iced_widget::core::mouse::Interaction {
+        if self.is_loading {
+            iced_widget::core::mouse::Interaction::Idle
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &iced_widget::core::widget::Tree,
+        renderer: &mut Renderer,
+        theme: &<Renderer as iced_widget::core::Renderer>::Theme,
+        style: &iced_widget::core::renderer::Style,
+        layout: iced_widget::core::Layout<'_>,
+        cursor: iced_widget::core::mouse::Cursor,
+        viewport: &iced_widget::core::Rectangle,
+    ) {
+        let mut children = layout.children();
+        let (content_layout, indicator_layout) =
+            (children.next().unwrap(), children.next().unwrap());
+
+        if self.is_loading {
+            self.indicator.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                indicator_layout,
+                cursor,
+                viewport,
+            );
+        } else {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut iced_widget::core::widget::Tree,
+        layout: iced_widget::core::Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_widget::core::overlay::Element<'b, M, Renderer>> {
+        crate::centered::overlay(&mut self.content, self.is_loading, tree, layout, renderer)
+    }
+}
+
+impl<'a, M, Renderer> std::convert::From<LoadingButton<'a, M, Renderer>>
+    for iced_widget::core::Element<'a, M, Renderer>
+where
+    M: 'a + Clone,
+    Renderer: 'a + iced_widget::canvas::Renderer + iced_widget::core::text::Renderer,
+    Renderer::Theme: StyleSheet + iced_widget::button::StyleSheet,
+    LoadingIndicator<Renderer::Theme>: iced_widget::canvas::Program<M, Renderer, State = DrawCache>,
+{
+    fn from(value: LoadingButton<'a, M, Renderer>) -> Self {
+        let content = Content {
+            content: value.content,
+            indicator: value.indicator.inherit_text_color(true).into(),
+            is_loading: value.is_loading,
+        };
+
+        let button = iced_widget::Button::new(iced_widget::core::Element::new(content))
+            .width(value.width)
+            .height(value.height)
+            .style(value.style);
+        let button = match value.padding {
+            Some(padding) => button.padding(padding),
+            None => button,
+        };
+        // While loading, the button gets disabled, so that presses do not produce
+        // its message, without keeping the events from reaching other listeners.
+        let button = match value.on_press {
+            Some(on_press) if !value.is_loading => button.on_press(on_press),
+            _ => button,
+        };
+
+        button.into()
+    }
+}
@@ -0,0 +1,298 @@
+//! Dim some content and show a loading indicator on top of it.
+
+use crate::{DrawCache, LoadingIndicator, StyleSheet, Widget};
+
+/// The default alpha channel value of the scrim drawn over the content while loading.
+pub const DEFAULT_SCRIM_ALPHA: f32 = 0.7;
+
+/// A container widget that draws its content and, while loading, dims it with a
+/// scrim derived from the theme's background (see [`crate::Appearance::background_color`])
+/// and centers a loading indicator [`Widget`] on top of it.
+///
+/// While loading, the content does not receive any mouse, touch or keyboard
+/// event (mouse and touch events over the overlay, as well as all keyboard
+/// events, get captured), so that it cannot be interacted with. Otherwise, the overlay is transparent to layout,
+/// operations and events.
+///
+/// ## Example
+/// ```
+/// let form = iced_widget::text("Some form");
+/// let overlay: iced_loading_indicator::LoadingOverlay<'_, ()> =
+///     iced_loading_indicator::LoadingOverlay::new(form, true)
+///         .indicator(iced_loading_indicator::Widget::with_size(60.0));
+/// ```
+pub struct LoadingOverlay<'a, M, Renderer = iced_widget::renderer::Renderer<iced_style::Theme>>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The wrapped content.
+    content: iced_widget::core::Element<'a, M, Renderer>,
+    /// The loading indicator drawn on top of the content while loading.
+    indicator: iced_widget::core::Element<'a, M, Renderer>,
+    /// Whether the content is loading.
+    is_loading: bool,
+    /// The style from which the scrim's color gets derived.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The scrim's alpha channel value.
+    scrim_alpha: f32,
+}
+
+impl<'a, M, Renderer> LoadingOverlay<'a, M, Renderer>
+where
+    M: 'a + Clone,
+    Renderer: 'a + iced_widget::canvas::Renderer + iced_widget::core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+    LoadingIndicator<Renderer::Theme>: iced_widget::canvas::Program<M, Renderer, State = DrawCache>,
+{
+    /// A factory method that wraps the provided `content`, showing the default
+    /// loading indicator [`Widget`] on top of it when `is_loading` is `true`.
+    pub fn new(
+        content: impl Into<iced_widget::core::Element<'a, M, Renderer>>,
+        is_loading: bool,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            indicator: Widget::new().into(),
+            is_loading,
+            style: Default::default(),
+            scrim_alpha: DEFAULT_SCRIM_ALPHA,
+        }
+    }
+
+    /// A setter method that can be used to specify the loading indicator [`Widget`]
+    /// shown on top of the content while loading.
+    pub fn indicator(self, value: Widget<'a, M, Renderer>) -> Self {
+        Self {
+            indicator: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the style (e.g. [`crate::Style`] for
+    /// the built-in theme) from which the scrim's color gets derived.
+    pub fn style(self, value: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        Self {
+            style: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the alpha channel value
+    /// of the scrim drawn over the content while loading.
+    pub fn scrim_alpha(self, value: f32) -> Self {
+        Self {
+            scrim_alpha: value,
+            ..self
+        }
+    }
+}
+
+impl<'a, M, Renderer> iced_widget::core::Widget<M, Renderer> for LoadingOverlay<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<iced_widget::core::widget::Tree> {
+        crate::centered::children(&self.content, &self.indicator)
+    }
+
+    fn diff(&self, tree: &mut iced_widget::core::widget::Tree) {
+        crate::centered::diff(tree, &self.content, &self.indicator);
+    }
+
+    fn width(&self) -> iced_widget::core::Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> iced_widget::core::Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_widget::core::layout::Limits,
+    ) -> iced_widget::core::layout::Node {
+        crate::centered::layout(&self.content, &self.indicator, renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut iced_widget::core::widget::Tree,
+        layout: iced_widget::core::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn iced_widget::core::widget::Operation<M>,
+    ) {
+        crate::centered::operate(&self.content, tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut iced_widget::core::widget::Tree,
+        event: iced_widget::core::Event,
+        layout: iced_widget::core::Layout<'_>,
+        cursor: iced_widget::core::mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_widget::core::Clipboard,
+        shell: &mut iced_widget::core::Shell<'_, M>,
+        viewport: &iced_widget::core::Rectangle,
+    ) -> iced_widget::core::event::Status {
+        let mut children = layout.children();
+        let (content_layout, indicator_layout) =
+            (children.next().unwrap(), children.next().unwrap());
+
+        if !self.is_loading {
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let _ = self.indicator.as_widget_mut().on_event(
+            &mut tree.children[1],
+            event.clone(),
+            indicator_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        match event {
+            iced_widget::core::Event::Mouse(_) | iced_widget::core::Event::Touch(_) => {
+                if cursor.is_over(layout.bounds()) {
+                    iced_widget::core::event::Status::Captured
+                } else {
+                    iced_widget::core::event::Status::Ignored
+                }
+            }
+            iced_widget::core::Event::Keyboard(_) => iced_widget::core::event::Status::Captured,
+            _ => self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &iced_widget::core::widget::Tree,
+        layout: iced_widget::core::Layout<'_>,
+        cursor: iced_widget::core::mouse::Cursor,
+        viewport: &iced_widget::core::Rectangle,
+        renderer: &Renderer,
+    ) -> iced_widget::core::mouse::Interaction {
+        if self.is_loading {
+            if cursor.is_over(layout.bounds()) {
+                iced_widget::core::mouse::Interaction::Working
+            } else {
+                iced_widget::core::mouse::Interaction::Idle
+            }
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &iced_widget::core::widget::Tree,
+        renderer: &mut Renderer,
+        theme: &<Renderer as iced_widget::core::Renderer>::Theme,
+        style: &iced_widget::core::renderer::Style,
+        layout: iced_widget::core::Layout<'_>,
+        cursor: iced_widget::core::mouse::Cursor,
+        viewport: &iced_widget::core::Rectangle,
+    ) {
+        let mut children = layout.children();
+        let (content_layout, indicator_layout) =
+            (children.next().unwrap(), children.next().unwrap());
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        if !self.is_loading {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let scrim = iced_widget::core::Color {
+            a: self.scrim_alpha,
+            ..theme
+                .appearance(&self.style)
+                .background_color
+                .unwrap_or(iced_widget::core::Color::BLACK)
+        };
+
+        // A new layer makes sure that the scrim and the indicator get drawn on top
+        // of everything the content drew (e.g. text).
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                iced_widget::core::renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: iced_widget::core::Color::TRANSPARENT,
+                },
+                scrim,
+            );
+
+            self.indicator.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                indicator_layout,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut iced_widget::core::widget::Tree,
+        layout: iced_widget::core::Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<iced_widget::core::overlay::Element<'b, M, Renderer>> {
+        crate::centered::overlay(&mut self.content, self.is_loading, tree, layout, renderer)
+    }
+}
+
+impl<'a, M, Renderer> std::convert::From<LoadingOverlay<'a, M, Renderer>>
+    for iced_widget::core::Element<'a, M, Renderer>
+where
+    M: 'a,
+    Renderer: 'a + iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: LoadingOverlay<'a, M, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,104 @@
+//! Choose the shape of the loading indicator's circles.
+
+/// The shape of each of the "circles" of a [`crate::Kind::Dots`] loading indicator.
+/// Whatever the shape, the circles get rotated around the indicator's center and
+/// colored according to the [`crate::Trail`] and the [`crate::Appearance`].
+#[derive(Clone, Debug, Default)]
+pub enum Shape {
+    /// A circle whose radius is the [`crate::Appearance`]'s `dot_radius_ratio`
+    /// times the indicator's radius.
+    #[default]
+    Circle,
+    /// A radial bar with rounded ends (as in the classic macOS/iOS activity indicator),
+    /// spanning from `inner` to `outer` times the indicator's radius, and whose width is
+    /// the [`crate::Appearance`]'s `dot_radius_ratio` times the indicator's radius
+    /// (e.g. `inner: 0.5` and `outer: 1.0`).
+    Bar {
+        /// The distance from the center at which the bar starts, relative to the indicator's radius.
+        inner: f32,
+        /// The distance from the center at which the bar ends, relative to the indicator's radius.
+        outer: f32,
+    },
+    /// A square circumscribing the circle that [`Shape::Circle`] would draw.
+    Square,
+    /// A user provided path, whose origin gets placed where the circle's center
+    /// would be, with the y axis pointing away from the indicator's center, and
+    /// whose coordinates get scaled by the indicator's radius (i.e. `1.0` is the
+    /// distance from the indicator's center to its edge).
+    Custom(std::rc::Rc<iced_widget::canvas::Path>),
+}
+
+impl Shape {
+    /// A factory method that wraps the provided `path` into a [`Shape::Custom`].
+    pub fn custom(path: iced_widget::canvas::Path) -> Self {
+        Self::Custom(std::rc::Rc::new(path))
+    }
+
+    /// Draws the shape onto the `frame`, which has been translated to the indicator's
+    /// center and rotated according to the circle's position, for a circle of radius
+    /// `point_size` centered `orbit` pixels away from the center of an indicator of
+    /// radius `radius`.
+    pub(crate) fn draw(
+        &self,
+        frame: &mut iced_widget::canvas::Frame,
+        radius: f32,
+        point_size: f32,
+        orbit: f32,
+        color: iced_widget::core::Color,
+    ) {
+        match self {
+            Self::Circle => frame.fill(
+                &iced_widget::canvas::Path::circle(
+                    iced_widget::core::Point::new(0.0, orbit),
+                    point_size,
+                ),
+                color,
+            ),
+            Self::Bar { inner, outer } => {
+                // The round caps stick out by half the width on each end.
+                let cap = point_size / 2.0;
+                let start = inner * radius + cap;
+                let end = (outer * radius - cap).max(start);
+                frame.stroke(
+                    &iced_widget::canvas::Path::line(
+                        iced_widget::core::Point::new(0.0, start),
+                        iced_widget::core::Point::new(0.0, end),
+                    ),
+                    iced_widget::canvas::Stroke::default()
+                        .with_color(color)
+                        .with_width(point_size)
+                        .with_line_cap(iced_widget::canvas::LineCap::Round),
+                );
+            }
+            Self::Square => frame.fill_rectangle(
+                iced_widget::core::Point::new(-point_size, orbit - point_size),
+                iced_widget::core::Size::new(point_size * 2.0, point_size * 2.0),
+                color,
+            ),
+            Self::Custom(path) => frame.with_save(|frame| {
+                frame.translate(iced_widget::core::Vector::new(0.0, orbit));
+                frame.scale(radius);
+                frame.fill(path, color);
+            }),
+        }
+    }
+}
+
+impl PartialEq for Shape {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Circle, Self::Circle) | (Self::Square, Self::Square) => true,
+            (
+                Self::Bar { inner, outer },
+                Self::Bar {
+                    inner: other_inner,
+                    outer: other_outer,
+                },
+            ) => inner == other_inner && outer == other_outer,
+            // Paths cannot be compared, hence the cached geometry only gets reused
+            // when the very same path is used.
+            (Self::Custom(path), Self::Custom(other_path)) => std::rc::Rc::ptr_eq(path, other_path),
+            _ => false,
+        }
+    }
+}
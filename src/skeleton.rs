@@ -0,0 +1,344 @@
+//! Show placeholder shapes with a moving highlight while content loads.
+
+use crate::{Inactive, StyleSheet};
+
+/// The default duration (in milliseconds) of a single sweep of the
+/// [`Skeleton`]'s highlight band across the widget.
+pub const DEFAULT_SWEEP_DURATION_MS: u64 = 1500;
+
+/// The default border radius (in pixels) of the [`Skeleton`]'s shapes.
+pub const DEFAULT_BORDER_RADIUS: f32 = 4.0;
+
+/// The default height (in pixels) of a [`Skeleton`] block.
+pub const DEFAULT_BLOCK_HEIGHT: f32 = 40.0;
+
+/// The width of the highlight band, relative to the widget's width.
+const BAND_WIDTH: f32 = 0.3;
+
+/// A structure used to keep track of the skeleton's internal state.
+struct State {
+    /// The position of the highlight band, in the `0.0..1.0` range.
+    phase: f32,
+    /// The moment at which the band was last moved.
+    last_tick: std::time::Instant,
+}
+
+/// A skeleton placeholder widget, which draws either a single block or a list
+/// of shapes (e.g. rectangles, or thin rectangles standing for lines of text)
+/// while content loads, with a highlight band sweeping across them.
+///
+/// The shapes' base color is the color of the inactive circles of a loading indicator
+/// of the same style (see [`Inactive`]), while the highlight band gets twice as opaque,
+/// so that, like the loading indicator, the skeleton follows the theme's palette (e.g.
+/// [`crate::Style::TextColor`] for the built-in theme). The band gets animated using the
+/// same redraw scheduling as the loading indicator [`crate::Widget`].
+///
+/// ## Example
+/// ```
+/// // Three lines of text, the last one being shorter.
+/// let skeleton: iced_loading_indicator::Skeleton<'_, ()> =
+///     iced_loading_indicator::Skeleton::with_shapes([
+///         iced_widget::core::Rectangle::new([0.0, 0.0].into(), [300.0, 12.0].into()),
+///         iced_widget::core::Rectangle::new([0.0, 20.0].into(), [300.0, 12.0].into()),
+///         iced_widget::core::Rectangle::new([0.0, 40.0].into(), [180.0, 12.0].into()),
+///     ]);
+/// ```
+pub struct Skeleton<'a, M, Renderer = iced_widget::renderer::Renderer<iced_style::Theme>>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The shapes to be drawn, relative to the widget's top left corner, if any.
+    /// When `None`, a single block filling the widget gets drawn.
+    shapes: Option<Vec<iced_widget::core::Rectangle>>,
+    /// The widget's width.
+    width: iced_widget::core::Length,
+    /// The widget's height.
+    height: iced_widget::core::Length,
+    /// The shapes' border radius (in pixels).
+    border_radius: f32,
+    /// The style from which the shapes' colors get derived.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The way in which the alpha channel of the shapes' base color gets determined.
+    inactive: Inactive,
+    /// The duration of a single sweep of the highlight band.
+    sweep_duration: std::time::Duration,
+    /// Binds the widget's type parameters, which are otherwise only used by its trait implementations.
+    _marker: std::marker::PhantomData<(&'a (), M, Renderer)>,
+}
+
+impl<'a, M, Renderer> Skeleton<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// A factory method that returns a skeleton made up of a single block, which
+    /// fills the available width and is [`DEFAULT_BLOCK_HEIGHT`] pixels high by default
+    /// (see [`Self::width`] and [`Self::height`]).
+    pub fn new() -> Self {
+        Self {
+            shapes: None,
+            width: iced_widget::core::Length::Fill,
+            height: iced_widget::core::Length::Fixed(DEFAULT_BLOCK_HEIGHT),
+            border_radius: DEFAULT_BORDER_RADIUS,
+            style: Default::default(),
+            inactive: Default::default(),
+            sweep_duration: std::time::Duration::from_millis(DEFAULT_SWEEP_DURATION_MS),
+            _marker: Default::default(),
+        }
+    }
+
+    /// A factory method that returns a skeleton made up of the provided `shapes`,
+    /// whose positions are relative to the widget's top left corner. The widget's size
+    /// defaults to the shapes' bounding box.
+    pub fn with_shapes(shapes: impl IntoIterator<Item = iced_widget::core::Rectangle>) -> Self {
+        let shapes: Vec<_> = shapes.into_iter().collect();
+        let (width, height) = shapes.iter().fold((0.0f32, 0.0f32), |(w, h), shape| {
+            (w.max(shape.x + shape.width), h.max(shape.y + shape.height))
+        });
+        Self {
+            shapes: Some(shapes),
+            width: iced_widget::core::Length::Fixed(width),
+            height: iced_widget::core::Length::Fixed(height),
+            ..Self::new()
+        }
+    }
+
+    /// A setter method that can be used to specify the widget's width.
+    pub fn width(self, value: impl Into<iced_widget::core::Length>) -> Self {
+        Self {
+            width: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the widget's height.
+    pub fn height(self, value: impl Into<iced_widget::core::Length>) -> Self {
+        Self {
+            height: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the shapes' border radius (in pixels).
+    pub fn border_radius(self, value: f32) -> Self {
+        Self {
+            border_radius: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the style (e.g. [`crate::Style`] for
+    /// the built-in theme) from which the shapes' colors get derived.
+    pub fn style(self, value: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        Self {
+            style: value.into(),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify how the alpha channel of the
+    /// shapes' base color gets determined (see [`Inactive`]).
+    pub fn inactive(self, value: Inactive) -> Self {
+        Self {
+            inactive: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the duration of a single
+    /// sweep of the highlight band across the widget.
+    pub fn sweep_duration(self, value: std::time::Duration) -> Self {
+        Self {
+            sweep_duration: value,
+            ..self
+        }
+    }
+}
+
+impl<'a, M, Renderer> Default for Skeleton<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A private helper function that returns the background of a shape spanning from
+/// `start` to `end` horizontally, for a highlight band centered on `center` and
+/// spanning `half_width` on each side.
+fn shape_background(
+    start: f32,
+    end: f32,
+    center: f32,
+    half_width: f32,
+    base: iced_widget::core::Color,
+    highlight: iced_widget::core::Color,
+) -> iced_widget::core::Background {
+    if end <= center - half_width || start >= center + half_width || end <= start {
+        return iced_widget::core::Background::Color(base);
+    }
+
+    let color_at = |x: f32| {
+        let strength = (1.0 - (x - center).abs() / half_width).max(0.0);
+        crate::mix_colors(base, highlight, strength)
+    };
+    let offset = |x: f32| (x - start) / (end - start);
+
+    // With an angle of `PI`, the gradient goes from left to right. Since `add_stop`
+    // overwrites the first slot whose offset is not lower than the new one, rather
+    // than inserting, the stops have to be added in ascending offset order.
+    let mut gradient =
+        iced_widget::core::gradient::Linear::new(iced_widget::core::Radians(std::f32::consts::PI))
+            .add_stop(0.0, color_at(start));
+    for x in [center - half_width, center, center + half_width] {
+        if x > start && x < end {
+            gradient = gradient.add_stop(offset(x), color_at(x));
+        }
+    }
+    gradient = gradient.add_stop(1.0, color_at(end));
+
+    iced_widget::core::Background::Gradient(gradient.into())
+}
+
+impl<'a, M, Renderer> iced_widget::core::Widget<M, Renderer> for Skeleton<'a, M, Renderer>
+where
+    Renderer: iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> iced_widget::core::Length {
+        self.width
+    }
+
+    fn height(&self) -> iced_widget::core::Length {
+        self.height
+    }
+
+    fn tag(&self) -> iced_widget::core::widget::tree::Tag {
+        iced_widget::core::widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> iced_widget::core::widget::tree::State {
+        iced_widget::core::widget::tree::State::new(State {
+            phase: 0.0,
+            last_tick: std::time::Instant::now(),
+        })
+    }
+
+    fn draw(
+        &self,
+        tree: &iced_widget::core::widget::Tree,
+        renderer: &mut Renderer,
+        theme: &<Renderer as iced_widget::core::Renderer>::Theme,
+        _style: &iced_widget::core::renderer::Style,
+        layout: iced_widget::core::Layout<'_>,
+        _cursor: iced_widget::core::mouse::Cursor,
+        _viewport: &iced_widget::core::Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = theme.appearance(&self.style);
+        let alpha = self
+            .inactive
+            .alpha_for(appearance.color, appearance.background_color);
+        let base = appearance
+            .inactive_color
+            .unwrap_or(iced_widget::core::Color {
+                a: alpha,
+                ..appearance.color
+            });
+        let highlight = iced_widget::core::Color {
+            a: (base.a * 2.0).min(1.0),
+            ..base
+        };
+
+        // The band enters from the left and leaves on the right.
+        let half_width = bounds.width * BAND_WIDTH / 2.0;
+        let center = bounds.x - half_width + (bounds.width + 2.0 * half_width) * state.phase;
+
+        let shapes = match &self.shapes {
+            Some(shapes) => shapes
+                .iter()
+                .map(|shape| iced_widget::core::Rectangle {
+                    x: bounds.x + shape.x,
+                    y: bounds.y + shape.y,
+                    ..*shape
+                })
+                .collect(),
+            None => vec![bounds],
+        };
+
+        for shape in shapes {
+            renderer.fill_quad(
+                iced_widget::core::renderer::Quad {
+                    bounds: shape,
+                    border_radius: self.border_radius.into(),
+                    border_width: 0.0,
+                    border_color: iced_widget::core::Color::TRANSPARENT,
+                },
+                shape_background(
+                    shape.x,
+                    shape.x + shape.width,
+                    center,
+                    half_width,
+                    base,
+                    highlight,
+                ),
+            );
+        }
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &iced_widget::core::layout::Limits,
+    ) -> iced_widget::core::layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(iced_widget::core::Size::ZERO);
+        iced_widget::core::layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut iced_widget::core::widget::Tree,
+        event: iced_widget::core::Event,
+        _layout: iced_widget::core::Layout<'_>,
+        _cursor: iced_widget::core::mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn iced_widget::core::Clipboard,
+        shell: &mut iced_widget::core::Shell<'_, M>,
+        _viewport: &iced_widget::core::Rectangle,
+    ) -> iced_widget::core::event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let iced_widget::core::Event::Window(
+            iced_widget::core::window::Event::RedrawRequested(now),
+        ) = event
+        {
+            let period = self.sweep_duration.as_secs_f32();
+            if period > 0.0 {
+                let elapsed = now.duration_since(state.last_tick);
+                state.phase = (state.phase + elapsed.as_secs_f32() / period).fract();
+            }
+            state.last_tick = now;
+            shell.request_redraw(iced_widget::core::window::RedrawRequest::NextFrame);
+        }
+
+        iced_widget::core::event::Status::Ignored
+    }
+}
+
+impl<'a, M, Renderer> std::convert::From<Skeleton<'a, M, Renderer>>
+    for iced_widget::core::Element<'a, M, Renderer>
+where
+    M: 'a,
+    Renderer: 'a + iced_widget::core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: Skeleton<'a, M, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
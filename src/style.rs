@@ -0,0 +1,177 @@
+//! Change the appearance of a loading indicator.
+
+/// The default radius of the circles, relative to the loading indicator's radius.
+pub const DEFAULT_DOT_RADIUS_RATIO: f32 = 0.15;
+
+/// The appearance of a loading indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The color of the active circle.
+    pub color: iced_widget::core::Color,
+    /// The base color of the circles trailing the active one, whose alpha channel
+    /// gets multiplied by the [`crate::Trail`]'s falloff. When `None`, `color` is used.
+    pub trail_color: Option<iced_widget::core::Color>,
+    /// The color of the inactive circles. When `None`, `color` is used along with the
+    /// [`crate::Trail`]'s inactive alpha channel.
+    pub inactive_color: Option<iced_widget::core::Color>,
+    /// The color of the track drawn behind the circles, if any.
+    pub track_color: Option<iced_widget::core::Color>,
+    /// The color of the background on which the loading indicator gets drawn, if
+    /// known, which is used by [`crate::Inactive::Auto`].
+    pub background_color: Option<iced_widget::core::Color>,
+    /// The radius of the circles, relative to the loading indicator's radius.
+    pub dot_radius_ratio: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            color: iced_widget::core::Color::BLACK,
+            trail_color: None,
+            inactive_color: None,
+            track_color: None,
+            background_color: None,
+            dot_radius_ratio: DEFAULT_DOT_RADIUS_RATIO,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a loading indicator.
+///
+/// This trait is implemented for [`iced_style::Theme`], but users relying on a custom
+/// theme type will need to implement it for that type in order to use the loading indicator.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default + Clone;
+
+    /// Produces the [`Appearance`] of the loading indicator.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The strength of an [`iced_style::theme::palette::Extended`] color, used to pick
+/// one of the `weak`, `base` and `strong` pairs of a given palette family.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strength {
+    /// The `weak` pair.
+    Weak,
+    /// The `base` pair.
+    #[default]
+    Base,
+    /// The `strong` pair.
+    Strong,
+}
+
+/// The style of a loading indicator for the built-in [`iced_style::Theme`].
+#[derive(Default, Clone)]
+pub enum Style {
+    /// Based on [`iced_style::theme::Palette`]'s `text` property.
+    #[default]
+    TextColor,
+    /// Based on [`iced_style::theme::Palette`]'s `primary` property.
+    PrimaryColor,
+    /// Used for a custom, user provided [`iced_widget::core::Color`].
+    CustomColor(iced_widget::core::Color),
+    /// Based on the color of one of the [`iced_style::theme::palette::Extended`]'s
+    /// `primary` pairs (e.g. `Primary(Strength::Strong)` matches the default button color).
+    Primary(Strength),
+    /// Based on the color of one of the [`iced_style::theme::palette::Extended`]'s `secondary` pairs.
+    Secondary(Strength),
+    /// Based on the color of one of the [`iced_style::theme::palette::Extended`]'s `success` pairs.
+    Success(Strength),
+    /// Based on the color of one of the [`iced_style::theme::palette::Extended`]'s `danger` pairs.
+    Danger(Strength),
+    /// Based on the color of one of the [`iced_style::theme::palette::Extended`]'s `background` pairs.
+    Background(Strength),
+    /// A custom style.
+    Custom(std::rc::Rc<dyn StyleSheet<Style = iced_style::Theme>>),
+}
+
+impl std::fmt::Debug for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TextColor => write!(f, "TextColor"),
+            Self::PrimaryColor => write!(f, "PrimaryColor"),
+            Self::CustomColor(color) => f.debug_tuple("CustomColor").field(color).finish(),
+            Self::Primary(strength) => f.debug_tuple("Primary").field(strength).finish(),
+            Self::Secondary(strength) => f.debug_tuple("Secondary").field(strength).finish(),
+            Self::Success(strength) => f.debug_tuple("Success").field(strength).finish(),
+            Self::Danger(strength) => f.debug_tuple("Danger").field(strength).finish(),
+            Self::Background(strength) => f.debug_tuple("Background").field(strength).finish(),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl<T: Fn(&iced_style::Theme) -> Appearance> StyleSheet for T {
+    type Style = iced_style::Theme;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        (self)(style)
+    }
+}
+
+impl<T: Fn(&iced_style::Theme) -> Appearance + 'static> From<T> for Style {
+    fn from(f: T) -> Self {
+        Self::Custom(std::rc::Rc::new(f))
+    }
+}
+
+impl StyleSheet for iced_style::Theme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let pick = |weak: iced_style::theme::palette::Pair,
+                    base: iced_style::theme::palette::Pair,
+                    strong: iced_style::theme::palette::Pair,
+                    strength: &Strength| match strength {
+            Strength::Weak => weak.color,
+            Strength::Base => base.color,
+            Strength::Strong => strong.color,
+        };
+
+        let color = match style {
+            Style::TextColor => self.palette().text,
+            Style::PrimaryColor => self.palette().primary,
+            Style::CustomColor(color) => *color,
+            Style::Primary(strength) => pick(
+                palette.primary.weak,
+                palette.primary.base,
+                palette.primary.strong,
+                strength,
+            ),
+            Style::Secondary(strength) => pick(
+                palette.secondary.weak,
+                palette.secondary.base,
+                palette.secondary.strong,
+                strength,
+            ),
+            Style::Success(strength) => pick(
+                palette.success.weak,
+                palette.success.base,
+                palette.success.strong,
+                strength,
+            ),
+            Style::Danger(strength) => pick(
+                palette.danger.weak,
+                palette.danger.base,
+                palette.danger.strong,
+                strength,
+            ),
+            Style::Background(strength) => pick(
+                palette.background.weak,
+                palette.background.base,
+                palette.background.strong,
+                strength,
+            ),
+            Style::Custom(custom) => return custom.appearance(self),
+        };
+
+        Appearance {
+            color,
+            background_color: Some(self.palette().background),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Configure the trail that follows the loading indicator's active circle.
+
+/// The alpha channel value used by default for the circles
+/// that are not part of the trail (i.e. the inactive circles).
+pub const DEFAULT_INACTIVE_ALPHA: f32 = 0.1;
+
+/// The alpha channel value used for the inactive circles when
+/// [`Trail::lighter_inactive`] is set to `true`.
+pub const LIGHTER_INACTIVE_ALPHA: f32 = 0.025;
+
+/// The way in which the alpha channel of the inactive circles (i.e. those that are
+/// neither active nor part of the trail) gets determined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Inactive {
+    /// A fixed alpha channel value.
+    Alpha(f32),
+    /// An alpha channel value computed at draw time from the contrast between the
+    /// circles' color and the background color reported by the theme (see
+    /// [`crate::Appearance::background_color`]), so that the inactive circles remain
+    /// subtle on both dark and light backgrounds. Falls back to [`DEFAULT_INACTIVE_ALPHA`]
+    /// when the theme does not report a background color.
+    Auto,
+}
+
+impl Default for Inactive {
+    fn default() -> Self {
+        Self::Alpha(DEFAULT_INACTIVE_ALPHA)
+    }
+}
+
+impl Inactive {
+    /// Returns the alpha channel value of the inactive circles, for circles
+    /// of the provided `color` drawn on the provided `background`, if known.
+    pub(crate) fn alpha_for(
+        &self,
+        color: iced_widget::core::Color,
+        background: Option<iced_widget::core::Color>,
+    ) -> f32 {
+        match (self, background) {
+            (Self::Alpha(alpha), _) => *alpha,
+            (Self::Auto, Some(background)) => auto_inactive_alpha(color, background),
+            (Self::Auto, None) => DEFAULT_INACTIVE_ALPHA,
+        }
+    }
+}
+
+/// The upper bound of the alpha channel value computed by [`Inactive::Auto`].
+const AUTO_INACTIVE_ALPHA_MAX: f32 = 0.3;
+
+/// A private helper function that returns the relative luminance of the
+/// provided `color`, as defined by the WCAG.
+fn relative_luminance(color: iced_widget::core::Color) -> f32 {
+    let linear = |c: f32| {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+/// A private helper function that computes the inactive alpha channel value
+/// for circles of the provided `color` drawn on the provided `background`.
+fn auto_inactive_alpha(
+    color: iced_widget::core::Color,
+    background: iced_widget::core::Color,
+) -> f32 {
+    let color_luminance = relative_luminance(color);
+    let background_luminance = relative_luminance(background);
+    let contrast = (color_luminance.max(background_luminance) + 0.05)
+        / (color_luminance.min(background_luminance) + 0.05);
+    // Light circles stand out more on a dark background than dark circles
+    // do on a light one, hence the lighter base value in the former case.
+    let base = if color_luminance > background_luminance {
+        LIGHTER_INACTIVE_ALPHA
+    } else {
+        DEFAULT_INACTIVE_ALPHA
+    };
+    // Lower contrast combinations need a higher alpha channel value to remain visible.
+    (base * (21.0 / contrast).sqrt()).clamp(base, AUTO_INACTIVE_ALPHA_MAX)
+}
+
+/// The curve dictating how the opacity of the circles trailing the
+/// active one falls off with their distance from it.
+#[derive(Clone, Default)]
+pub enum Falloff {
+    /// The alpha channel decreases linearly, reaching zero one step
+    /// past the end of the trail (e.g. `0.8`, `0.6`, `0.4` and `0.2` for a
+    /// trail of length `4`).
+    #[default]
+    Linear,
+    /// The alpha channel gets multiplied by the provided factor (which
+    /// should be in the `0.0..1.0` range) at each step away from the active circle.
+    Exponential(f32),
+    /// The alpha channel of the circle at a given distance is read from the
+    /// provided lookup table, where the first item corresponds to the circle directly
+    /// behind the active one. Distances past the end of the table use the inactive alpha.
+    Table(Vec<f32>),
+    /// The alpha channel is computed by a user provided closure, which receives
+    /// the distance from the active circle (starting at `1`) and the trail's length.
+    Custom(std::rc::Rc<dyn Fn(usize, usize) -> f32>),
+}
+
+impl Falloff {
+    /// A factory method that wraps the provided closure into a [`Falloff::Custom`].
+    pub fn custom(f: impl Fn(usize, usize) -> f32 + 'static) -> Self {
+        Self::Custom(std::rc::Rc::new(f))
+    }
+}
+
+impl PartialEq for Falloff {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Linear, Self::Linear) => true,
+            (Self::Exponential(factor), Self::Exponential(other_factor)) => factor == other_factor,
+            (Self::Table(table), Self::Table(other_table)) => table == other_table,
+            // Closures cannot be compared, hence the cached geometry only gets reused
+            // when the very same closure is used.
+            (Self::Custom(f), Self::Custom(other_f)) => std::rc::Rc::ptr_eq(f, other_f),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Debug for Falloff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linear => write!(f, "Linear"),
+            Self::Exponential(factor) => f.debug_tuple("Exponential").field(factor).finish(),
+            Self::Table(table) => f.debug_tuple("Table").field(table).finish(),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// The configuration of the trail following the loading indicator's
+/// active circle: how many circles it spans, how their opacity falls
+/// off, and the opacity of the remaining (inactive) circles.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trail {
+    /// The number of circles trailing the active one. When `None`, a third
+    /// of the indicator's number of circles is used.
+    length: Option<usize>,
+    /// The opacity falloff curve.
+    falloff: Falloff,
+    /// The way in which the inactive circles' alpha channel gets determined.
+    inactive: Inactive,
+}
+
+impl Trail {
+    /// A factory method that returns the default [`Trail`], which spans a third of
+    /// the indicator's circles, uses a [`Falloff::Linear`] curve and
+    /// [`DEFAULT_INACTIVE_ALPHA`] for the inactive circles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A setter method that can be used to specify the number of circles
+    /// trailing the active one. The value gets capped to the number of circles
+    /// minus one when drawing.
+    pub fn length(self, value: usize) -> Self {
+        Self {
+            length: Some(value),
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the opacity [`Falloff`] curve.
+    pub fn falloff(self, value: Falloff) -> Self {
+        Self {
+            falloff: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify how the alpha channel
+    /// of the inactive circles gets determined (see [`Inactive`]).
+    pub fn inactive(self, value: Inactive) -> Self {
+        Self {
+            inactive: value,
+            ..self
+        }
+    }
+
+    /// A setter method that can be used to specify the alpha channel value
+    /// used for the inactive circles. This is a shorthand for [`Inactive::Alpha`].
+    pub fn inactive_alpha(self, value: f32) -> Self {
+        self.inactive(Inactive::Alpha(value))
+    }
+
+    /// A setter method that can be used to specify whether a lighter
+    /// alpha channel value should be used for the inactive circles. For `false`,
+    /// [`DEFAULT_INACTIVE_ALPHA`] is used; for `true`, [`LIGHTER_INACTIVE_ALPHA`] is used.
+    /// The latter is useful on darker backgrounds, while the former will come out
+    /// nice on a lighter background.
+    pub fn lighter_inactive(self, value: bool) -> Self {
+        self.inactive_alpha(if value {
+            LIGHTER_INACTIVE_ALPHA
+        } else {
+            DEFAULT_INACTIVE_ALPHA
+        })
+    }
+
+    /// Returns the number of circles trailing the active one for
+    /// an indicator made up of `number_of_circles` circles.
+    pub(crate) fn resolved_length(&self, number_of_circles: usize) -> usize {
+        let length = self
+            .length
+            .unwrap_or(((number_of_circles as f32) / 3.0).round() as usize);
+        length.min(number_of_circles.saturating_sub(1))
+    }
+
+    /// Returns the alpha channel value of the inactive circles, for circles
+    /// of the provided `color` drawn on the provided `background`, if known.
+    pub(crate) fn inactive_alpha_for(
+        &self,
+        color: iced_widget::core::Color,
+        background: Option<iced_widget::core::Color>,
+    ) -> f32 {
+        self.inactive.alpha_for(color, background)
+    }
+
+    /// Returns the alpha channel value of the circle that is `distance` steps
+    /// behind the active one, for an indicator made up of `number_of_circles` circles,
+    /// where `inactive_alpha` is the value resolved by [`Self::inactive_alpha_for`].
+    pub(crate) fn alpha(
+        &self,
+        distance: usize,
+        number_of_circles: usize,
+        inactive_alpha: f32,
+    ) -> f32 {
+        if distance == 0 {
+            return 1.0;
+        }
+        let length = self.resolved_length(number_of_circles);
+        if distance > length {
+            return inactive_alpha;
+        }
+        let alpha = match &self.falloff {
+            Falloff::Linear => 1.0 - (distance as f32) / ((length + 1) as f32),
+            Falloff::Exponential(factor) => factor.powi(distance as i32),
+            Falloff::Table(table) => table.get(distance - 1).copied().unwrap_or(inactive_alpha),
+            Falloff::Custom(f) => f(distance, length),
+        };
+        alpha.clamp(0.0, 1.0)
+    }
+}